@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Derive macros for the `FromHex` and `ToHex` traits of the `hex` crate.
+//!
+//! Don't depend on this crate directly, enable the `derive` feature of `hex`
+//! instead, which re-exports the macros next to the traits.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Index, Member, Type};
+
+/// Derives `FromHex` for a newtype, by decoding into its only field.
+///
+/// The error type is the one of the field's `FromHex` impl.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHex;
+///
+/// #[derive(Debug, PartialEq, hex::FromHex)]
+/// struct TxId([u8; 4]);
+///
+/// assert_eq!(TxId::from_hex("6b697769"), Ok(TxId(*b"kiwi")));
+/// ```
+#[proc_macro_derive(FromHex)]
+pub fn derive_from_hex(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_hex(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `ToHex` for a newtype, by encoding its only field.
+///
+/// With the `#[hex(display)]` attribute, `Display` is implemented as well,
+/// formatting the newtype as lowercase hex.
+///
+/// # Example
+///
+/// ```
+/// use hex::ToHex;
+///
+/// #[derive(hex::ToHex)]
+/// #[hex(display)]
+/// struct TxId([u8; 4]);
+///
+/// assert_eq!(TxId(*b"kiwi").encode_hex_upper::<String>(), "6B697769");
+/// assert_eq!(TxId(*b"kiwi").to_string(), "6b697769");
+/// ```
+#[proc_macro_derive(ToHex, attributes(hex))]
+pub fn derive_to_hex(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_to_hex(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_from_hex(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let (member, ty) = newtype_field(input)?;
+    let name = &input.ident;
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#ty: ::hex::FromHex));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::hex::FromHex for #name #ty_generics #where_clause {
+            type Error = <#ty as ::hex::FromHex>::Error;
+
+            fn from_hex<__T: ::core::convert::AsRef<[u8]>>(
+                hex: __T,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::map(
+                    <#ty as ::hex::FromHex>::from_hex(hex),
+                    |value| #name { #member: value },
+                )
+            }
+        }
+    })
+}
+
+fn expand_to_hex(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let (member, ty) = newtype_field(input)?;
+    let name = &input.ident;
+
+    let mut display = false;
+    for attr in &input.attrs {
+        if attr.path().is_ident("hex") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("display") {
+                    display = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported hex attribute, expected `display`"))
+                }
+            })?;
+        }
+    }
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#ty: ::hex::ToHex));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let display = if display {
+        quote! {
+            impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::hex::ToHex::encode_hex_to(&self.#member, f)
+                }
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::hex::ToHex for #name #ty_generics #where_clause {
+            fn encode_hex<__T: ::core::iter::FromIterator<char>>(&self) -> __T {
+                ::hex::ToHex::encode_hex(&self.#member)
+            }
+
+            fn encode_hex_upper<__T: ::core::iter::FromIterator<char>>(&self) -> __T {
+                ::hex::ToHex::encode_hex_upper(&self.#member)
+            }
+
+            fn encode_hex_to<__W: ::core::fmt::Write + ?Sized>(
+                &self,
+                w: &mut __W,
+            ) -> ::core::fmt::Result {
+                ::hex::ToHex::encode_hex_to(&self.#member, w)
+            }
+
+            fn encode_hex_upper_to<__W: ::core::fmt::Write + ?Sized>(
+                &self,
+                w: &mut __W,
+            ) -> ::core::fmt::Result {
+                ::hex::ToHex::encode_hex_upper_to(&self.#member, w)
+            }
+        }
+
+        #display
+    })
+}
+
+// returns the member and type of the only field of a newtype struct.
+fn newtype_field(input: &DeriveInput) -> syn::Result<(Member, &Type)> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "hex can only be derived for structs",
+            ))
+        }
+    };
+
+    match fields {
+        Fields::Named(named) if named.named.len() == 1 => {
+            let field = &named.named[0];
+            let ident = field.ident.clone().expect("named fields have an ident");
+            Ok((Member::Named(ident), &field.ty))
+        }
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+            Ok((Member::Unnamed(Index::from(0)), &unnamed.unnamed[0].ty))
+        }
+        _ => Err(Error::new_spanned(
+            fields,
+            "hex can only be derived for structs with exactly one field",
+        )),
+    }
+}
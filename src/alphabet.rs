@@ -0,0 +1,277 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Custom digit alphabets.
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::encode_to_fmt_inner;
+use crate::{encode_to_slice_inner, FromHexError};
+
+const INVALID: u8 = 0xff;
+
+/// A set of 16 symbols used as hex digits.
+///
+/// The symbol at position `i` represents the nibble value `i`. Unlike the
+/// regular functions, decoding with an alphabet is case-sensitive: only the
+/// exact symbols of the alphabet are accepted. This also allows enforcing a
+/// canonical casing with [`Alphabet::LOWER`] or [`Alphabet::UPPER`].
+///
+/// # Example
+///
+/// ```
+/// use hex::Alphabet;
+///
+/// const SHUFFLED: Alphabet = Alphabet::new(b"qwertyuiopasdfgh");
+///
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// assert_eq!(hex::encode_with_alphabet([0x01, 0xfe], &SHUFFLED), "qwhg");
+/// assert_eq!(hex::decode_with_alphabet("qwhg", &SHUFFLED), Ok(vec![0x01, 0xfe]));
+///
+/// assert!(hex::decode_with_alphabet("ABCD", &Alphabet::LOWER).is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Alphabet {
+    encode: [u8; 16],
+    decode: [u8; 256],
+}
+
+impl Alphabet {
+    /// The standard lowercase alphabet `0123456789abcdef`.
+    pub const LOWER: Alphabet = Alphabet::new(crate::HEX_CHARS_LOWER);
+
+    /// The standard uppercase alphabet `0123456789ABCDEF`.
+    pub const UPPER: Alphabet = Alphabet::new(crate::HEX_CHARS_UPPER);
+
+    /// Creates an alphabet from 16 distinct ASCII symbols.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a symbol isn't ASCII or occurs more than once. When used to
+    /// initialize a constant, this is reported at compile time.
+    pub const fn new(symbols: &[u8; 16]) -> Alphabet {
+        let mut decode = [INVALID; 256];
+
+        let mut i = 0;
+        while i < symbols.len() {
+            let symbol = symbols[i];
+            assert!(symbol.is_ascii(), "alphabet symbols have to be ascii");
+            assert!(
+                decode[symbol as usize] == INVALID,
+                "alphabet symbols have to be distinct"
+            );
+
+            decode[symbol as usize] = i as u8;
+            i += 1;
+        }
+
+        Alphabet {
+            encode: *symbols,
+            decode,
+        }
+    }
+
+    /// Returns the symbols of this alphabet.
+    pub const fn symbols(&self) -> &[u8; 16] {
+        &self.encode
+    }
+
+    #[inline]
+    fn val(&self, c: u8, index: usize) -> Result<u8, FromHexError> {
+        match self.decode[c as usize] {
+            INVALID => Err(FromHexError::InvalidHexCharacter {
+                c: c as char,
+                index,
+            }),
+            value => Ok(value),
+        }
+    }
+}
+
+impl fmt::Debug for Alphabet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // the symbols are ascii, see `Alphabet::new`
+        let symbols = core::str::from_utf8(&self.encode).map_err(|_| fmt::Error)?;
+        f.debug_tuple("Alphabet").field(&symbols).finish()
+    }
+}
+
+impl PartialEq for Alphabet {
+    fn eq(&self, other: &Self) -> bool {
+        self.encode == other.encode
+    }
+}
+
+impl Eq for Alphabet {}
+
+/// Encodes `data` as hex string using the symbols of `alphabet`.
+///
+/// # Example
+///
+/// ```
+/// use hex::Alphabet;
+///
+/// assert_eq!(hex::encode_with_alphabet(b"kiwi", &Alphabet::UPPER), "6B697769");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_with_alphabet<T: AsRef<[u8]>>(data: T, alphabet: &Alphabet) -> String {
+    let data = data.as_ref();
+    let mut buf = String::with_capacity(data.len() * 2);
+
+    // writing into a `String` never fails
+    let _ = encode_to_fmt_inner(data, &mut buf, &alphabet.encode);
+    buf
+}
+
+/// Encodes some bytes into a mutable slice of bytes using the symbols of
+/// `alphabet`.
+///
+/// The output buffer has to be able to hold exactly `input.len() * 2` bytes,
+/// otherwise this function will return an error.
+///
+/// # Example
+///
+/// ```
+/// use hex::Alphabet;
+///
+/// let mut bytes = [0u8; 4];
+/// assert_eq!(hex::encode_to_slice_with_alphabet([0x12, 0xab], &mut bytes, &Alphabet::UPPER), Ok(()));
+/// assert_eq!(&bytes, b"12AB");
+/// ```
+pub fn encode_to_slice_with_alphabet<T: AsRef<[u8]>>(
+    input: T,
+    output: &mut [u8],
+    alphabet: &Alphabet,
+) -> Result<(), FromHexError> {
+    encode_to_slice_inner(input.as_ref(), output, &alphabet.encode)
+}
+
+/// Decodes a string of `alphabet` symbols into raw bytes.
+///
+/// Only the exact symbols of the alphabet are valid, any other character
+/// results in an [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter)
+/// error.
+///
+/// # Example
+///
+/// ```
+/// use hex::{Alphabet, FromHexError};
+///
+/// assert_eq!(hex::decode_with_alphabet("12AB", &Alphabet::UPPER), Ok(vec![0x12, 0xab]));
+/// assert_eq!(
+///     hex::decode_with_alphabet("12ab", &Alphabet::UPPER),
+///     Err(FromHexError::InvalidHexCharacter { c: 'a', index: 2 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_with_alphabet<T: AsRef<[u8]>>(
+    data: T,
+    alphabet: &Alphabet,
+) -> Result<Vec<u8>, FromHexError> {
+    let data = data.as_ref();
+    if data.len() % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+
+    let mut out = alloc::vec![0; data.len() / 2];
+    decode_to_slice_with_alphabet(data, &mut out, alphabet)?;
+    Ok(out)
+}
+
+/// Decodes a string of `alphabet` symbols into a mutable bytes slice.
+///
+/// Apart from the accepted symbols, this works exactly like
+/// [`decode_to_slice()`](crate::decode_to_slice).
+///
+/// # Example
+///
+/// ```
+/// use hex::Alphabet;
+///
+/// let mut bytes = [0u8; 2];
+/// assert_eq!(hex::decode_to_slice_with_alphabet("12ab", &mut bytes, &Alphabet::LOWER), Ok(()));
+/// assert_eq!(bytes, [0x12, 0xab]);
+/// ```
+pub fn decode_to_slice_with_alphabet<T: AsRef<[u8]>>(
+    data: T,
+    out: &mut [u8],
+    alphabet: &Alphabet,
+) -> Result<(), FromHexError> {
+    let data = data.as_ref();
+
+    if data.len() % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+    if data.len() / 2 != out.len() {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte =
+            alphabet.val(data[2 * i], 2 * i)? << 4 | alphabet.val(data[2 * i + 1], 2 * i + 1)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const REVERSED: Alphabet = Alphabet::new(b"fedcba9876543210");
+
+    #[test]
+    fn test_alphabet_slice() {
+        let mut encoded = [0; 4];
+        encode_to_slice_with_alphabet([0x01, 0xef], &mut encoded, &REVERSED).unwrap();
+        assert_eq!(&encoded, b"fe10");
+
+        let mut decoded = [0; 2];
+        decode_to_slice_with_alphabet(encoded, &mut decoded, &REVERSED).unwrap();
+        assert_eq!(decoded, [0x01, 0xef]);
+
+        assert_eq!(
+            decode_to_slice_with_alphabet("fe1g", &mut decoded, &REVERSED),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 })
+        );
+        assert_eq!(
+            decode_to_slice_with_alphabet("fe1", &mut decoded, &REVERSED),
+            Err(FromHexError::OddLength)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_alphabet_alloc() {
+        assert_eq!(
+            encode_with_alphabet("foobar", &Alphabet::LOWER),
+            "666f6f626172"
+        );
+        assert_eq!(
+            decode_with_alphabet("666F6F626172", &Alphabet::UPPER).unwrap(),
+            b"foobar"
+        );
+        assert_eq!(
+            decode_with_alphabet("666f6F626172", &Alphabet::UPPER),
+            Err(FromHexError::InvalidHexCharacter { c: 'f', index: 3 })
+        );
+    }
+
+    #[test]
+    fn test_alphabet() {
+        assert_eq!(REVERSED.symbols(), b"fedcba9876543210");
+        assert_eq!(Alphabet::new(b"0123456789abcdef"), Alphabet::LOWER);
+        assert_ne!(Alphabet::LOWER, Alphabet::UPPER);
+    }
+
+    #[test]
+    #[should_panic(expected = "alphabet symbols have to be distinct")]
+    fn test_alphabet_duplicate() {
+        let _ = Alphabet::new(b"0123456789abcdea");
+    }
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Support for `arrayvec` types.
+use arrayvec::{ArrayString, ArrayVec, CapacityError};
+
+use crate::{
+    decode_to_slice, decoded_len, encode_to_fmt_inner, FromHex, FromHexError, HEX_CHARS_LOWER,
+    HEX_CHARS_UPPER,
+};
+
+impl<const CAP: usize> FromHex for ArrayVec<u8, CAP> {
+    type Error = FromHexError;
+
+    /// Decodes up to `CAP` bytes, returning
+    /// [`InvalidStringLength`](FromHexError::InvalidStringLength) if the
+    /// bytes don't fit.
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        let hex = hex.as_ref();
+        let len = decoded_len(hex.len())?;
+        if len > CAP {
+            return Err(FromHexError::InvalidStringLength);
+        }
+
+        let mut out = ArrayVec::from([0; CAP]);
+        out.truncate(len);
+        decode_to_slice(hex, &mut out)?;
+        Ok(out)
+    }
+}
+
+/// Encodes `data` as hex string into an [`ArrayString`] with a capacity of
+/// `CAP` bytes, using lowercase characters.
+///
+/// Returns an error if the `data.len() * 2` digits don't fit into the string.
+///
+/// # Example
+///
+/// ```
+/// use arrayvec::ArrayString;
+///
+/// let hex: ArrayString<8> = hex::encode_arrayvec(b"kiwi")?;
+/// assert_eq!(hex.as_str(), "6b697769");
+///
+/// assert!(hex::encode_arrayvec::<6, _>(b"kiwi").is_err());
+/// # Ok::<(), arrayvec::CapacityError>(())
+/// ```
+pub fn encode_arrayvec<const CAP: usize, T: AsRef<[u8]>>(
+    data: T,
+) -> Result<ArrayString<CAP>, CapacityError> {
+    encode_arrayvec_inner(data.as_ref(), HEX_CHARS_LOWER)
+}
+
+/// Encodes `data` as hex string into an [`ArrayString`] with a capacity of
+/// `CAP` bytes, using uppercase characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_arrayvec()`].
+///
+/// # Example
+///
+/// ```
+/// use arrayvec::ArrayString;
+///
+/// let hex: ArrayString<4> = hex::encode_upper_arrayvec([0xca, 0xfe])?;
+/// assert_eq!(hex.as_str(), "CAFE");
+/// # Ok::<(), arrayvec::CapacityError>(())
+/// ```
+pub fn encode_upper_arrayvec<const CAP: usize, T: AsRef<[u8]>>(
+    data: T,
+) -> Result<ArrayString<CAP>, CapacityError> {
+    encode_arrayvec_inner(data.as_ref(), HEX_CHARS_UPPER)
+}
+
+fn encode_arrayvec_inner<const CAP: usize>(
+    data: &[u8],
+    table: &[u8; 16],
+) -> Result<ArrayString<CAP>, CapacityError> {
+    if data.len() * 2 > CAP {
+        return Err(CapacityError::new(()));
+    }
+
+    let mut buf = ArrayString::new();
+    encode_to_fmt_inner(data, &mut buf, table).map_err(|_| CapacityError::new(()))?;
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_encode_arrayvec() {
+        let hex: ArrayString<12> = encode_arrayvec("foobar").unwrap();
+        assert_eq!(hex.as_str(), "666f6f626172");
+
+        let hex: ArrayString<16> = encode_upper_arrayvec([0xab, 0xcd]).unwrap();
+        assert_eq!(hex.as_str(), "ABCD");
+
+        assert!(encode_arrayvec::<11, _>("foobar").is_err());
+    }
+
+    #[test]
+    fn test_from_hex_arrayvec() {
+        let bytes = ArrayVec::<u8, 8>::from_hex("6b697769").unwrap();
+        assert_eq!(&bytes[..], b"kiwi");
+        assert_eq!(ArrayVec::<u8, 4>::from_hex("").unwrap().len(), 0);
+
+        assert_eq!(
+            ArrayVec::<u8, 3>::from_hex("6b697769"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            ArrayVec::<u8, 8>::from_hex("6b6"),
+            Err(FromHexError::OddLength)
+        );
+        assert_eq!(
+            ArrayVec::<u8, 8>::from_hex("6b6g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 })
+        );
+    }
+}
@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Strict RFC 4648 Base16 encoding.
+//!
+//! [RFC 4648, section 8](https://www.rfc-editor.org/rfc/rfc4648#section-8)
+//! defines Base16 using the uppercase alphabet `0123456789ABCDEF`. The
+//! functions in this module follow it exactly: encoding always produces
+//! uppercase digits, and decoding rejects lowercase digits.
+//!
+//! # Example
+//!
+//! ```
+//! # fn main() -> Result<(), hex::FromHexError> {
+//! let mut encoded = [0u8; 4];
+//! hex::base16::encode_to_slice([0xbe, 0xef], &mut encoded)?;
+//! assert_eq!(&encoded, b"BEEF");
+//!
+//! let mut decoded = [0u8; 2];
+//! assert!(hex::base16::decode_to_slice("beef", &mut decoded).is_err());
+//! # Ok(())
+//! # }
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::{Alphabet, FromHexError};
+
+/// Encodes `data` as Base16 string.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::base16::encode(b"kiwi"), "6B697769");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode<T: AsRef<[u8]>>(data: T) -> String {
+    crate::encode_upper(data)
+}
+
+/// Encodes some bytes as Base16 into a mutable slice of bytes.
+///
+/// The output buffer has to be able to hold exactly `input.len() * 2` bytes,
+/// otherwise this function will return an error.
+pub fn encode_to_slice<T: AsRef<[u8]>>(input: T, output: &mut [u8]) -> Result<(), FromHexError> {
+    crate::encode_upper_to_slice(input, output)
+}
+
+/// Decodes a Base16 string into raw bytes.
+///
+/// Only the digits `0-9` and the uppercase letters `A-F` are valid, lowercase
+/// letters are rejected with [`InvalidCase`](FromHexError::InvalidCase) like
+/// [`decode_upper_strict()`](crate::decode_upper_strict) does.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::base16::decode("6B697769"), Ok(b"kiwi".to_vec()));
+/// assert_eq!(
+///     hex::base16::decode("6b697769"),
+///     Err(FromHexError::InvalidCase { c: 'b', index: 1 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    crate::decode_with_alphabet(data, &Alphabet::UPPER).map_err(case_error)
+}
+
+/// Decodes a Base16 string into a mutable bytes slice.
+///
+/// Apart from rejecting lowercase letters, this works exactly like
+/// [`decode_to_slice()`](crate::decode_to_slice).
+pub fn decode_to_slice<T: AsRef<[u8]>>(data: T, out: &mut [u8]) -> Result<(), FromHexError> {
+    crate::decode_to_slice_with_alphabet(data, out, &Alphabet::UPPER).map_err(case_error)
+}
+
+// reports lowercase digits, which aren't part of the uppercase alphabet, as
+// being in the wrong case.
+fn case_error(err: FromHexError) -> FromHexError {
+    match err {
+        FromHexError::InvalidHexCharacter {
+            c: c @ 'a'..='f',
+            index,
+        } => FromHexError::InvalidCase { c, index },
+        err => err,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    // test vectors from RFC 4648, section 10
+    #[cfg(feature = "alloc")]
+    const VECTORS: [(&str, &str); 7] = [
+        ("", ""),
+        ("f", "66"),
+        ("fo", "666F"),
+        ("foo", "666F6F"),
+        ("foob", "666F6F62"),
+        ("fooba", "666F6F6261"),
+        ("foobar", "666F6F626172"),
+    ];
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_rfc4648_vectors() {
+        for (data, encoded) in VECTORS {
+            assert_eq!(encode(data), encoded);
+            assert_eq!(decode(encoded).unwrap(), data.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_slice() {
+        let mut encoded = [0; 6];
+        encode_to_slice("foo", &mut encoded).unwrap();
+        assert_eq!(&encoded, b"666F6F");
+
+        let mut decoded = [0; 3];
+        decode_to_slice(encoded, &mut decoded).unwrap();
+        assert_eq!(&decoded, b"foo");
+
+        assert_eq!(
+            decode_to_slice("666f6F", &mut decoded),
+            Err(FromHexError::InvalidCase { c: 'f', index: 3 })
+        );
+        assert_eq!(
+            decode_to_slice("666g6F", &mut decoded),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_matches_upper_strict() {
+        for data in ["6b", "6B", "6G", "6", "0x6B"] {
+            assert_eq!(decode(data), crate::decode_upper_strict(data));
+        }
+    }
+}
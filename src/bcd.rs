@@ -0,0 +1,211 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Packed binary-coded decimal (BCD).
+//!
+//! Packed BCD stores one decimal digit per nibble, so it is hex restricted to
+//! the digits `0` to `9`. It is common in payment (e.g. ISO 8583) and telecom
+//! protocols.
+//!
+//! Encoding turns a string of decimal digits into packed bytes, decoding turns
+//! packed bytes back into their digits. Digit strings need to have an even
+//! length, just like hex strings.
+//!
+//! # Example
+//!
+//! ```
+//! # fn main() -> Result<(), hex::FromHexError> {
+//! let mut packed = [0u8; 3];
+//! hex::bcd::encode_to_slice("201912", &mut packed)?;
+//! assert_eq!(packed, [0x20, 0x19, 0x12]);
+//!
+//! let mut digits = [0u8; 6];
+//! hex::bcd::decode_to_slice(packed, &mut digits)?;
+//! assert_eq!(&digits, b"201912");
+//! # Ok(())
+//! # }
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::FromHexError;
+
+const fn digit(c: u8, index: usize) -> Result<u8, FromHexError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        _ => Err(FromHexError::InvalidHexCharacter {
+            c: c as char,
+            index,
+        }),
+    }
+}
+
+const fn nibble(value: u8, index: usize) -> Result<u8, FromHexError> {
+    match value {
+        0..=9 => Ok(b'0' + value),
+        _ => Err(FromHexError::InvalidHexCharacter {
+            c: crate::HEX_CHARS_LOWER[value as usize] as char,
+            index,
+        }),
+    }
+}
+
+/// Encodes a string of decimal digits into packed BCD bytes.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::bcd::encode("0042"), Ok(vec![0x00, 0x42]));
+/// assert_eq!(hex::bcd::encode("042"), Err(hex::FromHexError::OddLength));
+/// assert!(hex::bcd::encode("4a").is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode<T: AsRef<[u8]>>(digits: T) -> Result<Vec<u8>, FromHexError> {
+    let digits = digits.as_ref();
+    let mut out = alloc::vec![0; digits.len() / 2];
+    encode_to_slice(digits, &mut out)?;
+    Ok(out)
+}
+
+/// Encodes a string of decimal digits into a mutable slice of packed BCD
+/// bytes.
+///
+/// The output buffer has to be able to hold exactly `digits.len() / 2` bytes,
+/// otherwise this function will return an error.
+///
+/// # Example
+///
+/// ```
+/// let mut packed = [0u8; 2];
+/// assert_eq!(hex::bcd::encode_to_slice("1999", &mut packed), Ok(()));
+/// assert_eq!(packed, [0x19, 0x99]);
+/// ```
+pub fn encode_to_slice<T: AsRef<[u8]>>(digits: T, out: &mut [u8]) -> Result<(), FromHexError> {
+    let digits = digits.as_ref();
+
+    if digits.len() % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+    if digits.len() / 2 != out.len() {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = digit(digits[2 * i], 2 * i)? << 4 | digit(digits[2 * i + 1], 2 * i + 1)?;
+    }
+
+    Ok(())
+}
+
+/// Decodes packed BCD bytes into a string of decimal digits.
+///
+/// Every nibble of `data` has to be a decimal digit. Otherwise an
+/// [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) error is
+/// returned, containing the offending nibble as hex digit and its position
+/// in the digit string.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::bcd::decode([0x12, 0x34]), Ok("1234".to_owned()));
+/// assert_eq!(
+///     hex::bcd::decode([0x12, 0x3c]),
+///     Err(FromHexError::InvalidHexCharacter { c: 'c', index: 3 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode<T: AsRef<[u8]>>(data: T) -> Result<String, FromHexError> {
+    let data = data.as_ref();
+    let mut out = String::with_capacity(data.len() * 2);
+
+    for (i, byte) in data.iter().enumerate() {
+        out.push(nibble(byte >> 4, 2 * i)? as char);
+        out.push(nibble(byte & 0x0f, 2 * i + 1)? as char);
+    }
+
+    Ok(out)
+}
+
+/// Decodes packed BCD bytes into a mutable slice of ASCII decimal digits.
+///
+/// The output buffer has to be able to hold exactly `data.len() * 2` bytes,
+/// otherwise this function will return an error. Apart from that, this works
+/// like [`decode()`].
+///
+/// # Example
+///
+/// ```
+/// let mut digits = [0u8; 4];
+/// assert_eq!(hex::bcd::decode_to_slice([0x07, 0x31], &mut digits), Ok(()));
+/// assert_eq!(&digits, b"0731");
+/// ```
+pub fn decode_to_slice<T: AsRef<[u8]>>(data: T, out: &mut [u8]) -> Result<(), FromHexError> {
+    let data = data.as_ref();
+
+    if data.len() * 2 != out.len() {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    for (i, (byte, pair)) in data.iter().zip(out.chunks_exact_mut(2)).enumerate() {
+        pair[0] = nibble(byte >> 4, 2 * i)?;
+        pair[1] = nibble(byte & 0x0f, 2 * i + 1)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_encode_to_slice() {
+        let mut out = [0; 3];
+        encode_to_slice("123456", &mut out).unwrap();
+        assert_eq!(out, [0x12, 0x34, 0x56]);
+
+        assert_eq!(
+            encode_to_slice("12345f", &mut out),
+            Err(FromHexError::InvalidHexCharacter { c: 'f', index: 5 })
+        );
+        assert_eq!(
+            encode_to_slice("1234", &mut out),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            encode_to_slice("12345", &mut out),
+            Err(FromHexError::OddLength)
+        );
+    }
+
+    #[test]
+    fn test_decode_to_slice() {
+        let mut out = [0; 4];
+        decode_to_slice([0x98, 0x76], &mut out).unwrap();
+        assert_eq!(&out, b"9876");
+
+        assert_eq!(
+            decode_to_slice([0xa8, 0x76], &mut out),
+            Err(FromHexError::InvalidHexCharacter { c: 'a', index: 0 })
+        );
+        assert_eq!(
+            decode_to_slice([0x98], &mut out),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_decode() {
+        assert_eq!(encode("0123456789"), Ok(vec![0x01, 0x23, 0x45, 0x67, 0x89]));
+        assert_eq!(
+            decode([0x01, 0x23, 0x45, 0x67, 0x89]).unwrap(),
+            "0123456789"
+        );
+        assert_eq!(encode(""), Ok(vec![]));
+        assert_eq!(decode([]).unwrap(), "");
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Stack buffer for allocation-free encoding.
+use core::fmt;
+
+use crate::{byte2hex, HEX_CHARS_LOWER, HEX_CHARS_UPPER};
+
+/// A stack buffer for encoding up to `N` bytes without allocating.
+///
+/// The buffer holds the `2 * N` hex digits of the most recently formatted
+/// data and hands them out as `&str`, so there's no need to deal with output
+/// arrays and `str::from_utf8` manually.
+///
+/// # Example
+///
+/// ```
+/// let mut buf = hex::Buffer::<4>::new();
+///
+/// assert_eq!(buf.format(b"kiwi"), "6b697769");
+/// assert_eq!(buf.format_upper([0xca, 0xfe]), "CAFE");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Buffer<const N: usize> {
+    digits: [[u8; 2]; N],
+}
+
+impl<const N: usize> Buffer<N> {
+    /// Creates a new buffer.
+    #[inline]
+    pub const fn new() -> Buffer<N> {
+        Buffer {
+            digits: [[0; 2]; N],
+        }
+    }
+
+    /// Encodes `data` into the buffer using lowercase characters, returning
+    /// the encoded string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is longer than `N` bytes.
+    pub fn format<T: AsRef<[u8]>>(&mut self, data: T) -> &str {
+        self.format_inner(data.as_ref(), HEX_CHARS_LOWER)
+    }
+
+    /// Encodes `data` into the buffer using uppercase characters, returning
+    /// the encoded string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is longer than `N` bytes.
+    pub fn format_upper<T: AsRef<[u8]>>(&mut self, data: T) -> &str {
+        self.format_inner(data.as_ref(), HEX_CHARS_UPPER)
+    }
+
+    fn format_inner(&mut self, data: &[u8], table: &[u8; 16]) -> &str {
+        assert!(
+            data.len() <= N,
+            "data of {} bytes doesn't fit into a buffer of {} bytes",
+            data.len(),
+            N
+        );
+
+        for (byte, pair) in data.iter().zip(self.digits.iter_mut()) {
+            let (high, low) = byte2hex(*byte, table);
+            *pair = [high, low];
+        }
+
+        // SAFETY: `[[u8; 2]; N]` has the same layout as `[u8; 2 * N]`, and
+        // `data.len() <= N` has been checked above.
+        let digits = unsafe {
+            core::slice::from_raw_parts(self.digits.as_ptr().cast::<u8>(), data.len() * 2)
+        };
+
+        // SAFETY: the tables only contain ascii characters.
+        unsafe { core::str::from_utf8_unchecked(digits) }
+    }
+}
+
+impl<const N: usize> Default for Buffer<N> {
+    fn default() -> Self {
+        Buffer::new()
+    }
+}
+
+impl<const N: usize> fmt::Debug for Buffer<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Buffer").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format() {
+        let mut buf = Buffer::<6>::new();
+        assert_eq!(buf.format(b"foobar"), "666f6f626172");
+        assert_eq!(buf.format(b"kiwi"), "6b697769");
+        assert_eq!(buf.format_upper([0xab]), "AB");
+        assert_eq!(buf.format([]), "");
+
+        let mut buf = Buffer::<0>::new();
+        assert_eq!(buf.format([]), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "data of 5 bytes doesn't fit into a buffer of 4 bytes")]
+    fn test_format_too_long() {
+        Buffer::<4>::new().format(b"kiwis");
+    }
+}
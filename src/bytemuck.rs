@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Support for `bytemuck` types.
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use bytemuck::Pod;
+
+use crate::{decode_to_slice, FromHexError};
+
+/// Encodes the in-memory representation of a plain-old-data value as hex
+/// string, using lowercase characters.
+///
+/// The bytes are encoded in memory order, so multi-byte fields use the
+/// target's native byte order.
+///
+/// # Example
+///
+/// ```
+/// #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+/// #[repr(C)]
+/// struct Sample {
+///     id: [u8; 2],
+///     value: u16,
+/// }
+///
+/// let sample = Sample { id: [0xab, 0xcd], value: 0x0102_u16.to_be() };
+/// assert_eq!(hex::encode_pod(&sample), "abcd0102");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_pod<T: Pod>(value: &T) -> String {
+    crate::encode(bytemuck::bytes_of(value))
+}
+
+/// Decodes a hex string into the in-memory representation of a
+/// plain-old-data value.
+///
+/// This is the inverse of [`encode_pod()`](crate::encode_pod).
+///
+/// # Errors
+///
+/// Returns [`InvalidStringLength`](FromHexError::InvalidStringLength) if the
+/// input doesn't have exactly `2 * size_of::<T>()` digits, and
+/// [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) for an invalid
+/// digit.
+///
+/// # Example
+///
+/// ```
+/// let value: [u16; 2] = hex::decode_pod("01000200")?;
+/// assert_eq!(value.map(u16::from_le), [1, 2]);
+/// # Ok::<(), hex::FromHexError>(())
+/// ```
+pub fn decode_pod<T: Pod, U: AsRef<[u8]>>(data: U) -> Result<T, FromHexError> {
+    let mut value = T::zeroed();
+    decode_to_slice(data, bytemuck::bytes_of_mut(&mut value))?;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_decode_pod() {
+        assert_eq!(decode_pod::<u32, _>("2a2a2a2a"), Ok(0x2a2a2a2a));
+        assert_eq!(decode_pod::<[u8; 3], _>("6b6977"), Ok([0x6b, 0x69, 0x77]));
+        assert_eq!(decode_pod::<u16, _>("0102").map(u16::from_be), Ok(0x0102));
+        assert_eq!(
+            decode_pod::<u32, _>("0102"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_pod::<u16, _>("010g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_pod() {
+        assert_eq!(encode_pod(&[0xab_u8, 0xcd]), "abcd");
+        assert_eq!(encode_pod(&0x0102_u16.to_be()), "0102");
+        assert_eq!(encode_pod(&()), "");
+
+        let value = [1.5_f64, -0.25];
+        assert_eq!(decode_pod::<[f64; 2], _>(encode_pod(&value)), Ok(value));
+    }
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Support for `bytes` types.
+use bytes::{Bytes, BytesMut};
+
+use crate::{
+    decode_to_slice, decoded_len, encode_raw, FromHex, FromHexError, HEX_CHARS_LOWER,
+    HEX_CHARS_UPPER,
+};
+
+impl FromHex for BytesMut {
+    type Error = FromHexError;
+
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        let hex = hex.as_ref();
+
+        let mut out = BytesMut::zeroed(decoded_len(hex.len())?);
+        decode_to_slice(hex, &mut out)?;
+        Ok(out)
+    }
+}
+
+impl FromHex for Bytes {
+    type Error = FromHexError;
+
+    /// Decodes into a [`BytesMut`] which is then frozen, so the decoded bytes
+    /// aren't copied.
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        BytesMut::from_hex(hex).map(BytesMut::freeze)
+    }
+}
+
+/// Encodes `data` as hex digits into a [`Bytes`] buffer, using lowercase
+/// characters.
+///
+/// # Example
+///
+/// ```
+/// let hex = hex::encode_to_bytes(b"kiwi");
+/// assert_eq!(hex, "6b697769");
+/// ```
+#[must_use]
+pub fn encode_to_bytes<T: AsRef<[u8]>>(data: T) -> Bytes {
+    encode_to_bytes_inner(data.as_ref(), HEX_CHARS_LOWER)
+}
+
+/// Encodes `data` as hex digits into a [`Bytes`] buffer, using uppercase
+/// characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_to_bytes()`].
+///
+/// # Example
+///
+/// ```
+/// let hex = hex::encode_upper_to_bytes([0xca, 0xfe]);
+/// assert_eq!(hex, "CAFE");
+/// ```
+#[must_use]
+pub fn encode_upper_to_bytes<T: AsRef<[u8]>>(data: T) -> Bytes {
+    encode_to_bytes_inner(data.as_ref(), HEX_CHARS_UPPER)
+}
+
+fn encode_to_bytes_inner(data: &[u8], table: &[u8; 16]) -> Bytes {
+    let mut buf = BytesMut::zeroed(data.len() * 2);
+    encode_raw(data, &mut buf, table);
+    buf.freeze()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_hex_bytes() {
+        assert_eq!(Bytes::from_hex("6b697769"), Ok(Bytes::from_static(b"kiwi")));
+        assert_eq!(Bytes::from_hex(""), Ok(Bytes::new()));
+        assert_eq!(Bytes::from_hex("6b6"), Err(FromHexError::OddLength));
+
+        let bytes = BytesMut::from_hex("6B69").unwrap();
+        assert_eq!(&bytes[..], b"ki");
+        assert_eq!(
+            BytesMut::from_hex("6b6g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 })
+        );
+    }
+
+    #[test]
+    fn test_encode_to_bytes() {
+        assert_eq!(encode_to_bytes("foobar"), "666f6f626172");
+        assert_eq!(encode_upper_to_bytes([0xab, 0xcd]), "ABCD");
+        assert_eq!(encode_to_bytes([]), Bytes::new());
+    }
+}
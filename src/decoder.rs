@@ -0,0 +1,1046 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Configurable hex decoding.
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::hex_digit_to_value;
+use crate::{val, Case, FromHexError};
+
+/// A configurable hex decoder.
+///
+/// The plain [`decode()`](crate::decode) functions only accept one contiguous
+/// run of hex digits. Hex copied out of dumps, certificates or emails rarely
+/// looks like that, `Decoder` can be configured to accept such input as is.
+///
+/// Positions reported in errors always refer to the whole input, including
+/// any skipped characters.
+///
+/// # Example
+///
+/// ```
+/// use hex::Decoder;
+///
+/// let decoder = Decoder::new().ignore_whitespace(true);
+///
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(decoder.decode("6b69 7769\n2066 7275"), Ok(b"kiwi fru".to_vec()));
+///
+/// let decoder = Decoder::new().separators(":-");
+///
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(decoder.decode("00:1b-63"), Ok(vec![0x00, 0x1b, 0x63]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decoder<'a> {
+    ignore_whitespace: bool,
+    separators: &'a str,
+    prefix: Prefix,
+    left_pad: bool,
+    case: Option<Case>,
+}
+
+/// Whether a [`Decoder`] accepts input starting with `0x` or `0X`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prefix {
+    /// The input must not be prefixed, `0x` is rejected as invalid
+    /// character.
+    Forbidden,
+    /// The input may be prefixed.
+    Optional,
+    /// The input must be prefixed.
+    Required,
+}
+
+impl<'a> Decoder<'a> {
+    /// Creates a decoder only accepting hex digits, just like
+    /// [`decode()`](crate::decode).
+    pub const fn new() -> Decoder<'a> {
+        Decoder {
+            ignore_whitespace: false,
+            separators: "",
+            prefix: Prefix::Forbidden,
+            left_pad: false,
+            case: None,
+        }
+    }
+
+    /// Sets whether ASCII whitespace (spaces, tabs, line feeds, form feeds
+    /// and carriage returns) is skipped.
+    ///
+    /// Whitespace may appear anywhere, even between the two digits of a
+    /// byte.
+    #[must_use]
+    pub const fn ignore_whitespace(mut self, ignore_whitespace: bool) -> Decoder<'a> {
+        self.ignore_whitespace = ignore_whitespace;
+        self
+    }
+
+    /// Sets the characters which are skipped as separators, such as `":"`
+    /// or `"-_."`.
+    ///
+    /// Only ASCII characters are considered, just like whitespace they may
+    /// appear anywhere in the input. Skipped characters still count towards
+    /// the positions reported in errors.
+    ///
+    /// Underscores allow digit grouping as in Rust integer literals:
+    ///
+    /// ```
+    /// use hex::{Decoder, FromHexError};
+    ///
+    /// let decoder = Decoder::new().separators("_");
+    ///
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// assert_eq!(decoder.decode("dead_beef"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+    /// assert_eq!(
+    ///     decoder.decode("dead_beeg"),
+    ///     Err(FromHexError::InvalidHexCharacter { c: 'g', index: 8 })
+    /// );
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn separators(mut self, separators: &'a str) -> Decoder<'a> {
+        self.separators = separators;
+        self
+    }
+
+    /// Sets whether the input may or must start with `0x` or `0X`.
+    ///
+    /// The prefix has to come first, before any whitespace or separators.
+    ///
+    /// ```
+    /// use hex::{Decoder, FromHexError, Prefix};
+    ///
+    /// let decoder = Decoder::new().prefix(Prefix::Required);
+    ///
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// assert_eq!(decoder.decode("0x0a0b"), Ok(vec![0x0a, 0x0b]));
+    /// assert_eq!(
+    ///     decoder.decode("0a0b"),
+    ///     Err(FromHexError::InvalidHexCharacter { c: 'a', index: 1 })
+    /// );
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn prefix(mut self, prefix: Prefix) -> Decoder<'a> {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Sets whether an odd number of digits is accepted, as if the input was
+    /// padded with a leading zero.
+    ///
+    /// This is how hex numbers are usually read, e.g. `0xfff` is
+    /// `[0x0f, 0xff]`.
+    ///
+    /// ```
+    /// use hex::{Decoder, Prefix};
+    ///
+    /// let decoder = Decoder::new().prefix(Prefix::Required).left_pad(true);
+    ///
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(decoder.decode("0xfff"), Ok(vec![0x0f, 0xff]));
+    /// ```
+    #[must_use]
+    pub const fn left_pad(mut self, left_pad: bool) -> Decoder<'a> {
+        self.left_pad = left_pad;
+        self
+    }
+
+    /// Restricts the accepted digits to a single letter case.
+    ///
+    /// Digits of the other case are rejected with
+    /// [`InvalidCase`](FromHexError::InvalidCase), which is useful for
+    /// validating that input is in a canonical form. By default both cases
+    /// are accepted.
+    ///
+    /// ```
+    /// use hex::{Case, Decoder, FromHexError};
+    ///
+    /// let decoder = Decoder::new().only_case(Case::Lower);
+    ///
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// assert_eq!(decoder.decode("0abc"), Ok(vec![0x0a, 0xbc]));
+    /// assert_eq!(
+    ///     decoder.decode("0aBc"),
+    ///     Err(FromHexError::InvalidCase { c: 'B', index: 2 })
+    /// );
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn only_case(mut self, case: Case) -> Decoder<'a> {
+        self.case = Some(case);
+        self
+    }
+
+    // the length of the prefix at the start of `data`.
+    fn prefix_len(&self, data: &[u8]) -> Result<usize, FromHexError> {
+        let prefixed = matches!(data, [b'0', b'x' | b'X', ..]);
+
+        match (self.prefix, data) {
+            (Prefix::Optional | Prefix::Required, _) if prefixed => Ok(2),
+            (Prefix::Forbidden | Prefix::Optional, _) => Ok(0),
+            (Prefix::Required, [] | [b'0']) => Err(FromHexError::InvalidStringLength),
+            (Prefix::Required, [b'0', c, ..]) => Err(FromHexError::InvalidHexCharacter {
+                c: *c as char,
+                index: 1,
+            }),
+            (Prefix::Required, [c, ..]) => Err(FromHexError::InvalidHexCharacter {
+                c: *c as char,
+                index: 0,
+            }),
+        }
+    }
+
+    // whether `c` is skipped instead of being decoded as digit.
+    fn skips(&self, c: u8) -> bool {
+        (self.ignore_whitespace && c.is_ascii_whitespace())
+            || (c.is_ascii() && self.separators.as_bytes().contains(&c))
+    }
+
+    // decodes the digit `c` at position `idx`.
+    fn digit(&self, c: u8, idx: usize) -> Result<u8, FromHexError> {
+        let wrong_case = match self.case {
+            Some(Case::Lower) => c.is_ascii_uppercase(),
+            Some(Case::Upper) => c.is_ascii_lowercase(),
+            None => false,
+        };
+        let nibble = val(c, idx)?;
+
+        if wrong_case {
+            return Err(FromHexError::InvalidCase {
+                c: c as char,
+                index: idx,
+            });
+        }
+        Ok(nibble)
+    }
+
+    // decodes `data`, passing every decoded byte to `push` and every error
+    // to `error`. Decoding stops as soon as either of them fails, invalid
+    // digits are decoded as zero otherwise.
+    fn decode_with<F, E>(&self, data: &[u8], mut push: F, mut error: E) -> Result<(), FromHexError>
+    where
+        F: FnMut(u8) -> Result<(), FromHexError>,
+        E: FnMut(FromHexError) -> Result<(), FromHexError>,
+    {
+        let prefix_len = match self.prefix_len(data) {
+            Ok(prefix_len) => prefix_len,
+            // nothing after a broken prefix is meaningful
+            Err(err) => return error(err),
+        };
+        let digits = data[prefix_len..].iter().filter(|&&c| !self.skips(c));
+        // an odd digit goes into the low nibble of the first byte
+        let mut high = if self.left_pad && digits.count() % 2 != 0 {
+            Some(0)
+        } else {
+            None
+        };
+
+        for (i, &c) in data.iter().enumerate().skip(prefix_len) {
+            if self.skips(c) {
+                continue;
+            }
+
+            let nibble = match self.digit(c, i) {
+                Ok(nibble) => nibble,
+                Err(err) => {
+                    error(err)?;
+                    0
+                }
+            };
+            match high.take() {
+                Some(high) => push(high << 4 | nibble)?,
+                None => high = Some(nibble),
+            }
+        }
+
+        match high {
+            Some(_) => error(FromHexError::OddLength),
+            None => Ok(()),
+        }
+    }
+
+    /// Decodes `data` into a newly allocated `Vec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) for
+    /// the first character which is neither a digit nor skipped, and
+    /// [`OddLength`](FromHexError::OddLength) if the number of digits is odd.
+    #[cfg(feature = "alloc")]
+    pub fn decode<T: AsRef<[u8]>>(&self, data: T) -> Result<Vec<u8>, FromHexError> {
+        let data = data.as_ref();
+        let mut out = Vec::with_capacity(data.len() / 2);
+
+        self.decode_with(
+            data,
+            |byte| {
+                out.push(byte);
+                Ok(())
+            },
+            Err,
+        )?;
+        Ok(out)
+    }
+
+    /// Decodes `data` into a newly allocated `Vec`, reporting every problem
+    /// instead of only the first one.
+    ///
+    /// This is meant for tools pointing out all invalid characters of some
+    /// input at once. The errors are reported in the order of the input,
+    /// an [`OddLength`](FromHexError::OddLength) error comes last.
+    ///
+    /// # Errors
+    ///
+    /// Returns all of the errors [`decode`](Self::decode) would return one at
+    /// a time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::{Decoder, FromHexError};
+    ///
+    /// assert_eq!(
+    ///     Decoder::new().decode_report("0g1h2"),
+    ///     Err(vec![
+    ///         FromHexError::InvalidHexCharacter { c: 'g', index: 1 },
+    ///         FromHexError::InvalidHexCharacter { c: 'h', index: 3 },
+    ///         FromHexError::OddLength,
+    ///     ])
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn decode_report<T: AsRef<[u8]>>(&self, data: T) -> Result<Vec<u8>, Vec<FromHexError>> {
+        let data = data.as_ref();
+        let mut out = Vec::with_capacity(data.len() / 2);
+        let mut errors = Vec::new();
+
+        // all errors are collected, so this never fails
+        let _ = self.decode_with(
+            data,
+            |byte| {
+                out.push(byte);
+                Ok(())
+            },
+            |err| {
+                errors.push(err);
+                Ok(())
+            },
+        );
+
+        if errors.is_empty() {
+            Ok(out)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Decodes `data` into `out`, returning the number of decoded bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`decode`](Self::decode), and
+    /// [`InvalidStringLength`](FromHexError::InvalidStringLength) if `out` is
+    /// too small.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::Decoder;
+    ///
+    /// let mut buf = [0; 8];
+    /// let len = Decoder::new().ignore_whitespace(true).decode_to_slice("6b 69\t77 69", &mut buf);
+    /// assert_eq!(len, Ok(4));
+    /// assert_eq!(&buf[..4], b"kiwi");
+    /// ```
+    pub fn decode_to_slice<T: AsRef<[u8]>>(
+        &self,
+        data: T,
+        out: &mut [u8],
+    ) -> Result<usize, FromHexError> {
+        let mut len = 0;
+
+        self.decode_with(
+            data.as_ref(),
+            |byte| {
+                let slot = out.get_mut(len).ok_or(FromHexError::InvalidStringLength)?;
+                *slot = byte;
+                len += 1;
+                Ok(())
+            },
+            Err,
+        )?;
+        Ok(len)
+    }
+}
+
+impl Default for Decoder<'_> {
+    fn default() -> Self {
+        Decoder::new()
+    }
+}
+
+/// Decodes a hex string into raw bytes, skipping any ASCII whitespace.
+///
+/// This is the lenient counterpart of [`decode()`](crate::decode), for hex
+/// copied out of dumps or wrapped over several lines. Positions reported in
+/// errors refer to the whole input, including the whitespace.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::decode_lenient("6b 69\n77 69\n"), Ok(b"kiwi".to_vec()));
+/// assert_eq!(hex::decode_lenient(" 6 b "), Ok(b"k".to_vec()));
+/// assert_eq!(hex::decode_lenient("6b 6"), Err(FromHexError::OddLength));
+/// assert_eq!(
+///     hex::decode_lenient("6b, 69"),
+///     Err(FromHexError::InvalidHexCharacter { c: ',', index: 2 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_lenient<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    Decoder::new().ignore_whitespace(true).decode(data)
+}
+
+/// Decodes a hex string into raw bytes, skipping any of the characters in
+/// `separators`.
+///
+/// Only ASCII separators are supported, see [`Decoder::separators`].
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::decode_with_separators("de:ad:be:ef", ":"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(hex::decode_with_separators("dead-beef", "-_"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(
+///     hex::decode_with_separators("de:ad-be", ":"),
+///     Err(FromHexError::InvalidHexCharacter { c: '-', index: 5 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_with_separators<T: AsRef<[u8]>>(
+    data: T,
+    separators: &str,
+) -> Result<Vec<u8>, FromHexError> {
+    Decoder::new().separators(separators).decode(data)
+}
+
+/// Decodes a hex string with an optional `0x` or `0X` prefix into raw bytes.
+///
+/// Positions reported in errors refer to the whole input, including the
+/// prefix. Use a [`Decoder`] with [`Prefix::Required`] to reject input
+/// without prefix.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::decode_prefixed("0xdeadbeef"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(hex::decode_prefixed("deadbeef"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(
+///     hex::decode_prefixed("0xdeadbeeg"),
+///     Err(FromHexError::InvalidHexCharacter { c: 'g', index: 9 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_prefixed<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    Decoder::new().prefix(Prefix::Optional).decode(data)
+}
+
+/// Decodes hex in any of the common notations into raw bytes.
+///
+/// This is the most lenient way of decoding hex offered by this crate,
+/// meant for input typed or pasted by humans. The following is accepted:
+///
+/// - an optional `0x` or `0X` prefix, possibly after leading whitespace,
+/// - ASCII whitespace anywhere, e.g. for hex wrapped over several lines,
+/// - `:` and `-` separators, as in MAC addresses or fingerprints,
+/// - `_` separators, as in Rust integer literals,
+/// - upper- and lowercase digits, even mixed.
+///
+/// Positions reported in errors refer to the whole input. Use the strict
+/// [`decode()`](crate::decode) for anything which must be in canonical form,
+/// or a [`Decoder`] to pick the accepted notations.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// let expected = Ok(vec![0xde, 0xad, 0xbe, 0xef]);
+///
+/// assert_eq!(hex::parse_any("deadbeef"), expected);
+/// assert_eq!(hex::parse_any("  0xDEADbeef\n"), expected);
+/// assert_eq!(hex::parse_any("de:ad:be:ef"), expected);
+/// assert_eq!(hex::parse_any("DE-AD-BE-EF"), expected);
+/// assert_eq!(hex::parse_any("dead beef"), expected);
+/// assert_eq!(hex::parse_any("0xdead_beef"), expected);
+/// assert_eq!(
+///     hex::parse_any("de,ad"),
+///     Err(FromHexError::InvalidHexCharacter { c: ',', index: 2 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_any<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    let data = data.as_ref();
+    let start = data
+        .iter()
+        .position(|c| !c.is_ascii_whitespace())
+        .unwrap_or(data.len());
+
+    Decoder::new()
+        .prefix(Prefix::Optional)
+        .ignore_whitespace(true)
+        .separators(":-_")
+        .decode(&data[start..])
+        .map_err(|err| err.offset(start))
+}
+
+/// Decodes a hex string into raw bytes, padding an odd number of digits with
+/// a leading zero.
+///
+/// This is meant for hex numbers, where the leading zero is commonly
+/// omitted. Use [`decode()`](crate::decode) if the input has to consist of
+/// whole bytes.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::decode_left_padded("abc"), Ok(vec![0x0a, 0xbc]));
+/// assert_eq!(hex::decode_left_padded("f"), Ok(vec![0x0f]));
+/// assert_eq!(hex::decode_left_padded("0abc"), Ok(vec![0x0a, 0xbc]));
+/// assert_eq!(
+///     hex::decode_left_padded("abg"),
+///     Err(FromHexError::InvalidHexCharacter { c: 'g', index: 2 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_left_padded<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    Decoder::new().left_pad(true).decode(data)
+}
+
+/// Decodes a hex string into raw bytes, only accepting lowercase digits.
+///
+/// This is useful when the input has to be in canonical form, e.g. for
+/// values which are compared or hashed as text.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::decode_lower_strict("deadbeef"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(
+///     hex::decode_lower_strict("deadBeef"),
+///     Err(FromHexError::InvalidCase { c: 'B', index: 4 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_lower_strict<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    Decoder::new().only_case(Case::Lower).decode(data)
+}
+
+/// Decodes a hex string into raw bytes, only accepting uppercase digits.
+///
+/// This is the alphabet of Base16 as defined by RFC 4648.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::decode_upper_strict("DEADBEEF"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(
+///     hex::decode_upper_strict("DEADbEEF"),
+///     Err(FromHexError::InvalidCase { c: 'b', index: 4 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_upper_strict<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    Decoder::new().only_case(Case::Upper).decode(data)
+}
+
+/// How [`decode_lossy`] deals with invalid characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lossy {
+    /// Invalid characters are skipped, the remaining digits are paired up
+    /// as if they were never there.
+    Skip,
+    /// Every byte with an invalid digit is replaced by the given byte, so
+    /// the output keeps the alignment of the input.
+    Replace(u8),
+}
+
+/// Decodes as much of a hex string as possible, instead of failing on the
+/// first problem.
+///
+/// Returns the decoded bytes and the number of problems which were found.
+/// With [`Lossy::Skip`], every invalid character counts as a problem. With
+/// [`Lossy::Replace`], every replaced byte counts as a single problem. In
+/// both cases, a single digit left over at the end is dropped and counted
+/// as problem too.
+///
+/// This is meant for recovering data from damaged or noisy input, use
+/// [`decode()`](crate::decode) or a [`Decoder`] wherever errors should be
+/// reported.
+///
+/// # Example
+///
+/// ```
+/// use hex::Lossy;
+///
+/// assert_eq!(hex::decode_lossy("6b69??7769", Lossy::Skip), (b"kiwi".to_vec(), 2));
+/// assert_eq!(hex::decode_lossy("6b69?77769", Lossy::Replace(b'?')), (b"ki?wi".to_vec(), 1));
+/// assert_eq!(hex::decode_lossy("6b697769", Lossy::Skip), (b"kiwi".to_vec(), 0));
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn decode_lossy<T: AsRef<[u8]>>(data: T, lossy: Lossy) -> (Vec<u8>, usize) {
+    let data = data.as_ref();
+    let mut out = Vec::with_capacity(data.len() / 2);
+    let mut problems = 0;
+
+    match lossy {
+        Lossy::Skip => {
+            let mut high = None;
+            for &c in data {
+                let nibble = match hex_digit_to_value(c) {
+                    Some(nibble) => nibble,
+                    None => {
+                        problems += 1;
+                        continue;
+                    }
+                };
+                match high.take() {
+                    Some(high) => out.push(high << 4 | nibble),
+                    None => high = Some(nibble),
+                }
+            }
+            if high.is_some() {
+                problems += 1;
+            }
+        }
+        Lossy::Replace(replacement) => {
+            let pairs = data.chunks_exact(2);
+            if !pairs.remainder().is_empty() {
+                problems += 1;
+            }
+            for pair in pairs {
+                match (hex_digit_to_value(pair[0]), hex_digit_to_value(pair[1])) {
+                    (Some(high), Some(low)) => out.push(high << 4 | low),
+                    _ => {
+                        out.push(replacement);
+                        problems += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    (out, problems)
+}
+
+/// Returns an iterator decoding a list of hex strings separated by
+/// `delimiter`, such as `"0a0b, 0c0d"`.
+///
+/// Every item is trimmed of surrounding ASCII whitespace before it's decoded
+/// with [`decode()`](crate::decode). If `delimiter` is ASCII whitespace
+/// itself, such as `'\n'`, items are separated by runs of any ASCII
+/// whitespace instead. Input consisting of nothing but whitespace contains
+/// no items.
+///
+/// Positions reported in errors refer to the whole input, so errors can be
+/// pointed out in context.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// let items: Result<Vec<_>, _> = hex::decode_many("6b69, 7769,", ',').collect();
+/// assert_eq!(items, Ok(vec![b"ki".to_vec(), b"wi".to_vec(), vec![]]));
+///
+/// let items: Vec<_> = hex::decode_many("6b69\n  7769\r\n 776g\n", '\n').collect();
+/// assert_eq!(
+///     items,
+///     [
+///         Ok(b"ki".to_vec()),
+///         Ok(b"wi".to_vec()),
+///         Err(FromHexError::InvalidHexCharacter { c: 'g', index: 17 }),
+///     ]
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_many(data: &str, delimiter: char) -> DecodeMany<'_> {
+    let rest = if data.trim_matches(is_ascii_whitespace).is_empty() {
+        None
+    } else {
+        Some(data)
+    };
+
+    DecodeMany {
+        data,
+        rest,
+        delimiter,
+    }
+}
+
+/// An iterator decoding a list of hex strings.
+///
+/// This struct is created by [`decode_many`].
+#[derive(Debug, Clone)]
+#[cfg(feature = "alloc")]
+pub struct DecodeMany<'a> {
+    data: &'a str,
+    rest: Option<&'a str>,
+    delimiter: char,
+}
+
+#[cfg(feature = "alloc")]
+fn is_ascii_whitespace(c: char) -> bool {
+    c.is_ascii_whitespace()
+}
+
+#[cfg(feature = "alloc")]
+impl Iterator for DecodeMany<'_> {
+    type Item = Result<Vec<u8>, FromHexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+
+        let (item, rest) = if self.delimiter.is_ascii_whitespace() {
+            let rest = rest.trim_start_matches(is_ascii_whitespace);
+            match rest.find(is_ascii_whitespace) {
+                Some(end) => (&rest[..end], Some(&rest[end..])),
+                None => (rest, None),
+            }
+        } else {
+            match rest.find(self.delimiter) {
+                Some(end) => (&rest[..end], Some(&rest[end + self.delimiter.len_utf8()..])),
+                None => (rest, None),
+            }
+        };
+        // no empty item after trailing whitespace
+        self.rest = rest.filter(|rest| {
+            !self.delimiter.is_ascii_whitespace()
+                || !rest.trim_matches(is_ascii_whitespace).is_empty()
+        });
+
+        let item = item.trim_matches(is_ascii_whitespace);
+        let offset = item.as_ptr() as usize - self.data.as_ptr() as usize;
+        Some(crate::decode(item).map_err(|err| err.offset(offset)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::iter::FusedIterator for DecodeMany<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_lenient() {
+        assert_eq!(decode_lenient(""), Ok(vec![]));
+        assert_eq!(decode_lenient(" \t\r\n\x0c"), Ok(vec![]));
+        assert_eq!(
+            decode_lenient("DE AD\r\nbe\tef"),
+            Ok(vec![0xde, 0xad, 0xbe, 0xef])
+        );
+        assert_eq!(
+            decode_lenient("de ad\u{a0}"),
+            Err(FromHexError::InvalidHexCharacter {
+                c: '\u{c2}',
+                index: 5
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decoder_strict() {
+        assert_eq!(Decoder::new().decode("6b697769"), Ok(b"kiwi".to_vec()));
+        assert_eq!(
+            Decoder::new().decode("6b 69"),
+            Err(FromHexError::InvalidHexCharacter { c: ' ', index: 2 })
+        );
+        assert_eq!(Decoder::new().decode("6b6"), Err(FromHexError::OddLength));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_with_separators() {
+        assert_eq!(decode_with_separators("", ":"), Ok(vec![]));
+        assert_eq!(decode_with_separators("::", ":"), Ok(vec![]));
+        assert_eq!(
+            decode_with_separators("d:e.a_d", ":._"),
+            Ok(vec![0xde, 0xad])
+        );
+        assert_eq!(
+            decode_with_separators("de ad", ":"),
+            Err(FromHexError::InvalidHexCharacter { c: ' ', index: 2 })
+        );
+        assert_eq!(
+            decode_with_separators("de\u{b7}ad", "\u{b7}"),
+            Err(FromHexError::InvalidHexCharacter {
+                c: '\u{c2}',
+                index: 2
+            })
+        );
+        assert_eq!(
+            Decoder::new()
+                .ignore_whitespace(true)
+                .separators(",")
+                .decode("de, ad,\nbe, ef"),
+            Ok(vec![0xde, 0xad, 0xbe, 0xef])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_prefixed() {
+        assert_eq!(decode_prefixed(""), Ok(vec![]));
+        assert_eq!(decode_prefixed("0x"), Ok(vec![]));
+        assert_eq!(decode_prefixed("0X0a"), Ok(vec![0x0a]));
+        assert_eq!(decode_prefixed("0a"), Ok(vec![0x0a]));
+        assert_eq!(decode_prefixed("0x0"), Err(FromHexError::OddLength));
+        assert_eq!(
+            decode_prefixed("0x0x"),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 3 })
+        );
+        assert_eq!(
+            decode_prefixed(" 0x0a"),
+            Err(FromHexError::InvalidHexCharacter { c: ' ', index: 0 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decoder_prefix() {
+        let decoder = Decoder::new().prefix(Prefix::Required);
+        assert_eq!(decoder.decode("0x"), Ok(vec![]));
+        assert_eq!(decoder.decode(""), Err(FromHexError::InvalidStringLength));
+        assert_eq!(decoder.decode("0"), Err(FromHexError::InvalidStringLength));
+        assert_eq!(
+            decoder.decode("00"),
+            Err(FromHexError::InvalidHexCharacter { c: '0', index: 1 })
+        );
+        assert_eq!(
+            decoder.decode("x0"),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 0 })
+        );
+
+        let decoder = Decoder::new().prefix(Prefix::Forbidden);
+        assert_eq!(
+            decoder.decode("0x0a"),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 1 })
+        );
+
+        let decoder = Decoder::new()
+            .prefix(Prefix::Required)
+            .ignore_whitespace(true);
+        assert_eq!(decoder.decode("0x 0a 0b"), Ok(vec![0x0a, 0x0b]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_parse_any() {
+        assert_eq!(parse_any(""), Ok(vec![]));
+        assert_eq!(parse_any(" \n"), Ok(vec![]));
+        assert_eq!(parse_any(" 0x "), Ok(vec![]));
+        assert_eq!(
+            parse_any("\t0X00:1B-63 84\r\n45e6"),
+            Ok(vec![0x00, 0x1b, 0x63, 0x84, 0x45, 0xe6])
+        );
+        assert_eq!(parse_any(" 0x0a0"), Err(FromHexError::OddLength));
+        assert_eq!(
+            parse_any(" 0a 0x"),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 5 })
+        );
+        assert_eq!(parse_any("0a_0b_"), Ok(vec![0x0a, 0x0b]));
+        assert_eq!(
+            parse_any("0x_0a__0g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 8 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_left_padded() {
+        assert_eq!(decode_left_padded(""), Ok(vec![]));
+        assert_eq!(decode_left_padded("0"), Ok(vec![0x00]));
+        assert_eq!(decode_left_padded("123"), Ok(vec![0x01, 0x23]));
+        assert_eq!(decode_left_padded("1234"), Ok(vec![0x12, 0x34]));
+        assert_eq!(
+            decode_left_padded("12 3"),
+            Err(FromHexError::InvalidHexCharacter { c: ' ', index: 2 })
+        );
+
+        let decoder = Decoder::new().separators("_").left_pad(true);
+        assert_eq!(decoder.decode("f_ffff"), Ok(vec![0x0f, 0xff, 0xff]));
+        assert_eq!(decoder.decode("__f__"), Ok(vec![0x0f]));
+
+        let mut buf = [0; 2];
+        assert_eq!(decoder.decode_to_slice("1_23", &mut buf), Ok(2));
+        assert_eq!(buf, [0x01, 0x23]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_lower_strict() {
+        assert_eq!(decode_lower_strict(""), Ok(vec![]));
+        assert_eq!(
+            decode_lower_strict("0123456789abcdef"),
+            Ok(vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef])
+        );
+        assert_eq!(
+            decode_lower_strict("F0"),
+            Err(FromHexError::InvalidCase { c: 'F', index: 0 })
+        );
+        assert_eq!(
+            decode_lower_strict("0G"),
+            Err(FromHexError::InvalidHexCharacter { c: 'G', index: 1 })
+        );
+
+        let decoder = Decoder::new()
+            .prefix(Prefix::Optional)
+            .only_case(Case::Lower);
+        assert_eq!(decoder.decode("0Xff"), Ok(vec![0xff]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_upper_strict() {
+        assert_eq!(decode_upper_strict(""), Ok(vec![]));
+        assert_eq!(
+            decode_upper_strict("0123456789ABCDEF"),
+            Ok(vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef])
+        );
+        assert_eq!(
+            decode_upper_strict("0f"),
+            Err(FromHexError::InvalidCase { c: 'f', index: 1 })
+        );
+        assert_eq!(
+            decode_upper_strict("g0"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 0 })
+        );
+
+        let decoder = Decoder::new()
+            .prefix(Prefix::Required)
+            .only_case(Case::Upper);
+        assert_eq!(decoder.decode("0xFF"), Ok(vec![0xff]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_lossy_skip() {
+        assert_eq!(decode_lossy("", Lossy::Skip), (vec![], 0));
+        assert_eq!(decode_lossy("xyz", Lossy::Skip), (vec![], 3));
+        assert_eq!(decode_lossy("0 a-0\nb", Lossy::Skip), (vec![0x0a, 0x0b], 3));
+        assert_eq!(decode_lossy("0a0", Lossy::Skip), (vec![0x0a], 1));
+        assert_eq!(decode_lossy("0a0 ", Lossy::Skip), (vec![0x0a], 2));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_lossy_replace() {
+        let lossy = Lossy::Replace(0);
+        assert_eq!(decode_lossy("", lossy), (vec![], 0));
+        assert_eq!(decode_lossy("zz0aFF", lossy), (vec![0x00, 0x0a, 0xff], 1));
+        assert_eq!(decode_lossy("0zz0ff", lossy), (vec![0, 0, 0xff], 2));
+        assert_eq!(decode_lossy("0aF", lossy), (vec![0x0a], 1));
+        assert_eq!(decode_lossy("0a0 ", lossy), (vec![0x0a, 0], 1));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decoder_decode_report() {
+        let decoder = Decoder::new();
+        assert_eq!(decoder.decode_report(""), Ok(vec![]));
+        assert_eq!(decoder.decode_report("0a0b"), Ok(vec![0x0a, 0x0b]));
+        assert_eq!(
+            decoder.decode_report("0a0"),
+            Err(vec![FromHexError::OddLength])
+        );
+
+        let decoder = Decoder::new()
+            .prefix(Prefix::Required)
+            .ignore_whitespace(true)
+            .only_case(Case::Lower);
+        assert_eq!(
+            decoder.decode_report("0x 0A xx"),
+            Err(vec![
+                FromHexError::InvalidCase { c: 'A', index: 4 },
+                FromHexError::InvalidHexCharacter { c: 'x', index: 6 },
+                FromHexError::InvalidHexCharacter { c: 'x', index: 7 },
+            ])
+        );
+        assert_eq!(
+            decoder.decode_report("0y0a0"),
+            Err(vec![FromHexError::InvalidHexCharacter { c: 'y', index: 1 }])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_many() {
+        assert_eq!(decode_many("", ',').count(), 0);
+        assert_eq!(decode_many(" \n ", ',').count(), 0);
+        assert_eq!(decode_many(" \n ", '\n').count(), 0);
+
+        let items: Vec<_> = decode_many(",0a,\t0b0c ,", ',').collect();
+        assert_eq!(
+            items,
+            [Ok(vec![]), Ok(vec![0x0a]), Ok(vec![0x0b, 0x0c]), Ok(vec![])]
+        );
+
+        let items: Vec<_> = decode_many("0a\u{b7}0b", '\u{b7}').collect();
+        assert_eq!(items, [Ok(vec![0x0a]), Ok(vec![0x0b])]);
+
+        let items: Vec<_> = decode_many("\n0a 0b\t\t0c\n\n", ' ').collect();
+        assert_eq!(items, [Ok(vec![0x0a]), Ok(vec![0x0b]), Ok(vec![0x0c])]);
+
+        let items: Vec<_> = decode_many("0a;0b0;0x", ';').collect();
+        assert_eq!(
+            items,
+            [
+                Ok(vec![0x0a]),
+                Err(FromHexError::OddLength),
+                Err(FromHexError::InvalidHexCharacter { c: 'x', index: 8 })
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decoder_decode_to_slice() {
+        let decoder = Decoder::new().ignore_whitespace(true);
+
+        let mut buf = [0; 2];
+        assert_eq!(decoder.decode_to_slice("", &mut buf), Ok(0));
+        assert_eq!(decoder.decode_to_slice(" 0a 0b ", &mut buf), Ok(2));
+        assert_eq!(buf, [0x0a, 0x0b]);
+        assert_eq!(
+            decoder.decode_to_slice("0a 0b 0c", &mut buf),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decoder.decode_to_slice("0a 0", &mut buf),
+            Err(FromHexError::OddLength)
+        );
+    }
+}
@@ -0,0 +1,356 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Lazy hex formatting.
+use core::fmt::{self, Write};
+
+use crate::{byte2hex, encode_to_fmt_inner, HEX_CHARS_LOWER, HEX_CHARS_UPPER};
+
+/// A wrapper displaying bytes as hex string.
+///
+/// The bytes are encoded on the fly while formatting, nothing is allocated.
+/// Use [`display()`] to create one.
+///
+/// Lowercase characters are used by [`Display`](fmt::Display) and
+/// [`LowerHex`](fmt::LowerHex), uppercase ones by
+/// [`UpperHex`](fmt::UpperHex). The usual formatter flags are supported:
+/// width, fill and alignment pad the output (aligned to the left by default),
+/// the alternate flag `#` adds a `0x` prefix, and the precision limits the
+/// number of displayed digits.
+///
+/// # Example
+///
+/// ```
+/// let data = [0xca, 0xfe];
+///
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// assert_eq!(format!("{:X}", hex::display(&data)), "CAFE");
+/// assert_eq!(format!("{:#x}", hex::display(&data)), "0xcafe");
+/// assert_eq!(format!("[{:>8}]", hex::display(&data)), "[    cafe]");
+/// assert_eq!(format!("[{:*^#10X}]", hex::display(&data)), "[**0xCAFE**]");
+/// assert_eq!(format!("{:.3}", hex::display(&data)), "caf");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexDisplay<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> HexDisplay<'a> {
+    /// Creates a wrapper displaying `data`.
+    pub const fn new(data: &'a [u8]) -> HexDisplay<'a> {
+        HexDisplay { data }
+    }
+}
+
+impl HexDisplay<'_> {
+    fn fmt_inner(&self, f: &mut fmt::Formatter, table: &[u8; 16]) -> fmt::Result {
+        let prefix = if f.alternate() { "0x" } else { "" };
+        let digits = f.precision().map_or(self.data.len() * 2, |precision| {
+            precision.min(self.data.len() * 2)
+        });
+        let len = prefix.len() + digits;
+
+        let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, (padding + 1) / 2),
+            _ => (0, padding),
+        };
+        let fill = f.fill();
+
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        f.write_str(prefix)?;
+        encode_to_fmt_inner(&self.data[..digits / 2], f, table)?;
+        if digits % 2 == 1 {
+            let (high, _) = byte2hex(self.data[digits / 2], table);
+            f.write_char(high as char)?;
+        }
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for HexDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_inner(f, HEX_CHARS_LOWER)
+    }
+}
+
+impl fmt::LowerHex for HexDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_inner(f, HEX_CHARS_LOWER)
+    }
+}
+
+impl fmt::UpperHex for HexDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_inner(f, HEX_CHARS_UPPER)
+    }
+}
+
+/// Wraps `data` so that it's displayed as hex string using lowercase
+/// characters.
+///
+/// Unlike [`encode()`](crate::encode), nothing is encoded until the returned
+/// value is actually formatted, e.g. by a logging macro that may skip it.
+///
+/// # Example
+///
+/// ```
+/// let data = [0xde, 0xad, 0xbe, 0xef];
+///
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(format!("digest: {}", hex::display(&data)), "digest: deadbeef");
+/// ```
+pub fn display<T: AsRef<[u8]> + ?Sized>(data: &T) -> HexDisplay<'_> {
+    HexDisplay::new(data.as_ref())
+}
+
+/// A wrapper displaying only the first and last bytes of a hex string.
+///
+/// The omitted bytes in the middle are replaced by an ellipsis (`…`). Use
+/// [`abbrev()`] to create one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Abbrev<'a> {
+    data: &'a [u8],
+    head: usize,
+    tail: usize,
+}
+
+impl<'a> Abbrev<'a> {
+    /// Creates a wrapper displaying the first `head` and the last `tail`
+    /// bytes of `data`.
+    pub const fn new(data: &'a [u8], head: usize, tail: usize) -> Abbrev<'a> {
+        Abbrev { data, head, tail }
+    }
+
+    fn fmt_inner(&self, f: &mut fmt::Formatter, table: &[u8; 16]) -> fmt::Result {
+        if self.head.saturating_add(self.tail) >= self.data.len() {
+            return encode_to_fmt_inner(self.data, f, table);
+        }
+
+        encode_to_fmt_inner(&self.data[..self.head], f, table)?;
+        f.write_char('…')?;
+        encode_to_fmt_inner(&self.data[self.data.len() - self.tail..], f, table)
+    }
+}
+
+impl fmt::Display for Abbrev<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_inner(f, HEX_CHARS_LOWER)
+    }
+}
+
+impl fmt::LowerHex for Abbrev<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_inner(f, HEX_CHARS_LOWER)
+    }
+}
+
+impl fmt::UpperHex for Abbrev<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_inner(f, HEX_CHARS_UPPER)
+    }
+}
+
+/// Wraps `data` so that only its first `head` and last `tail` bytes are
+/// displayed, separated by an ellipsis.
+///
+/// If `data` is short enough, it's displayed completely. Just like
+/// [`display()`], nothing is encoded until the returned value is formatted.
+///
+/// # Example
+///
+/// ```
+/// let hash = [0xde, 0xad, 0xbe, 0xef, 0x00, 0x00, 0x00, 0xbe, 0xef];
+///
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// assert_eq!(hex::abbrev(&hash, 3, 3).to_string(), "deadbe…00beef");
+/// assert_eq!(format!("{:X}", hex::abbrev(&hash, 1, 1)), "DE…EF");
+/// assert_eq!(hex::abbrev(&hash[..4], 2, 2).to_string(), "deadbeef");
+/// # }
+/// ```
+pub fn abbrev<T: AsRef<[u8]> + ?Sized>(data: &T, head: usize, tail: usize) -> Abbrev<'_> {
+    Abbrev::new(data.as_ref(), head, tail)
+}
+
+/// A wrapper displaying a collection of byte slices as list of hex strings.
+///
+/// By default, the list looks like `[dead, beef]`, the brackets and the
+/// separator can be changed. Since the items are encoded while formatting,
+/// the collection has to be cloneable, e.g. a reference to a `Vec` or slice.
+///
+/// # Example
+///
+/// ```
+/// use hex::HexList;
+///
+/// let keys = [[0xde, 0xad], [0xbe, 0xef]];
+///
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// assert_eq!(HexList::new(&keys).to_string(), "[dead, beef]");
+/// assert_eq!(format!("{:X}", HexList::new(&keys).separator(" ").brackets("", "")), "DEAD BEEF");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexList<'a, I> {
+    items: I,
+    separator: &'a str,
+    open: &'a str,
+    close: &'a str,
+}
+
+impl<'a, I> HexList<'a, I> {
+    /// Creates a wrapper displaying `items` in square brackets, separated by
+    /// commas.
+    pub const fn new(items: I) -> HexList<'a, I> {
+        HexList {
+            items,
+            separator: ", ",
+            open: "[",
+            close: "]",
+        }
+    }
+
+    /// Sets the separator written between two items.
+    #[must_use]
+    pub fn separator(mut self, separator: &'a str) -> HexList<'a, I> {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the brackets written around the items.
+    #[must_use]
+    pub fn brackets(mut self, open: &'a str, close: &'a str) -> HexList<'a, I> {
+        self.open = open;
+        self.close = close;
+        self
+    }
+}
+
+impl<I> HexList<'_, I>
+where
+    I: IntoIterator + Clone,
+    I::Item: AsRef<[u8]>,
+{
+    fn fmt_inner(&self, f: &mut fmt::Formatter, table: &[u8; 16]) -> fmt::Result {
+        f.write_str(self.open)?;
+        for (i, item) in self.items.clone().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.separator)?;
+            }
+            encode_to_fmt_inner(item.as_ref(), f, table)?;
+        }
+        f.write_str(self.close)
+    }
+}
+
+impl<I> fmt::Display for HexList<'_, I>
+where
+    I: IntoIterator + Clone,
+    I::Item: AsRef<[u8]>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_inner(f, HEX_CHARS_LOWER)
+    }
+}
+
+impl<I> fmt::LowerHex for HexList<'_, I>
+where
+    I: IntoIterator + Clone,
+    I::Item: AsRef<[u8]>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_inner(f, HEX_CHARS_LOWER)
+    }
+}
+
+impl<I> fmt::UpperHex for HexList<'_, I>
+where
+    I: IntoIterator + Clone,
+    I::Item: AsRef<[u8]>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_inner(f, HEX_CHARS_UPPER)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod test {
+    use super::*;
+    use alloc::{format, string::ToString, vec};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(display("kiwi").to_string(), "6b697769");
+        assert_eq!(display(&[0u8; 0]).to_string(), "");
+        assert_eq!(format!("{}", display(&[0xab; 200][..])), "ab".repeat(200));
+    }
+
+    #[test]
+    fn test_display_flags() {
+        let data = display(&[0x0a, 0xbc]);
+        assert_eq!(format!("{:x}", data), "0abc");
+        assert_eq!(format!("{:X}", data), "0ABC");
+        assert_eq!(format!("{:#}", data), "0x0abc");
+        assert_eq!(format!("{:#X}", data), "0x0ABC");
+
+        assert_eq!(format!("{:6}|", data), "0abc  |");
+        assert_eq!(format!("{:>6}", data), "  0abc");
+        assert_eq!(format!("{:-^9}", data), "--0abc---");
+        assert_eq!(format!("{:_>#8}", data), "__0x0abc");
+        assert_eq!(format!("{:2}", data), "0abc");
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let data = display(&[0x12, 0x34, 0x56]);
+        assert_eq!(format!("{:.0}", data), "");
+        assert_eq!(format!("{:.1}", data), "1");
+        assert_eq!(format!("{:.4}", data), "1234");
+        assert_eq!(format!("{:.5}", data), "12345");
+        assert_eq!(format!("{:.10}", data), "123456");
+        assert_eq!(format!("{:#.2}", data), "0x12");
+        assert_eq!(format!("{:>6.3}", data), "   123");
+        assert_eq!(format!("{:.*}", 2, display(&[0xab; 32])), "ab");
+    }
+
+    #[test]
+    fn test_abbrev() {
+        let data = [0x01, 0x02, 0x03, 0x04, 0x05];
+        assert_eq!(abbrev(&data, 1, 2).to_string(), "01…0405");
+        assert_eq!(abbrev(&data, 0, 1).to_string(), "…05");
+        assert_eq!(abbrev(&data, 2, 0).to_string(), "0102…");
+        assert_eq!(abbrev(&data, 2, 3).to_string(), "0102030405");
+        assert_eq!(abbrev(&data, usize::MAX, 1).to_string(), "0102030405");
+        assert_eq!(abbrev(&[0u8; 0], 0, 0).to_string(), "");
+    }
+
+    #[test]
+    fn test_hex_list() {
+        let items = vec![vec![0x01], vec![], vec![0xab, 0xcd]];
+        assert_eq!(HexList::new(&items).to_string(), "[01, , abcd]");
+        assert_eq!(format!("{:X}", HexList::new(&items)), "[01, , ABCD]");
+        assert_eq!(
+            HexList::new(&items)
+                .separator("|")
+                .brackets("<", ">")
+                .to_string(),
+            "<01||abcd>"
+        );
+
+        let hashes: &[[u8; 2]] = &[[0x12, 0x34]];
+        assert_eq!(HexList::new(hashes).to_string(), "[1234]");
+        assert_eq!(HexList::new(&[] as &[&[u8]]).to_string(), "[]");
+        assert_eq!(HexList::new(["ab", "cd"]).to_string(), "[6162, 6364]");
+    }
+}
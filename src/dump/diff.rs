@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Side by side hexdumps of differences.
+use alloc::string::String;
+
+use core::fmt;
+
+use super::{Dumper, OffsetFormat, Style};
+
+impl Dumper {
+    /// Dumps the lines which differ between `old` and `new` side by side,
+    /// into a newly allocated `String`.
+    ///
+    /// See [`diff_to_fmt`](Self::diff_to_fmt) for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::dump::Dumper;
+    ///
+    /// let old = b"Hello, world! Hello, hex!";
+    /// let new = b"Hello, world! Hello, Hex!!";
+    ///
+    /// assert_eq!(
+    ///     Dumper::new().columns(8).diff(old, new),
+    ///     "00000010: 6c6c 6f2c 2068 6578  llo, hex | 6c6c 6f2c 2048 6578  llo, Hex\n\
+    ///      00000018: 21                   !        | 2121                 !!\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn diff<T: AsRef<[u8]>, U: AsRef<[u8]>>(&self, old: T, new: U) -> String {
+        let mut buf = String::new();
+
+        // writing into a `String` never fails
+        let _ = self.diff_to_fmt(old, new, &mut buf);
+        buf
+    }
+
+    /// Writes the lines which differ between `old` and `new` side by side
+    /// into a [`fmt::Write`] sink.
+    ///
+    /// Both inputs are split into lines of [`columns`](Self::columns) bytes.
+    /// Every line whose bytes differ is written once, with the offset
+    /// followed by the old bytes on the left and the new bytes on the right,
+    /// separated by ` | `. If one of the inputs is shorter, its side is left
+    /// blank. Lines which are equal are skipped, so the output is empty if
+    /// the inputs are equal.
+    pub fn diff_to_fmt<T, U, W>(&self, old: T, new: U, w: &mut W) -> fmt::Result
+    where
+        T: AsRef<[u8]>,
+        U: AsRef<[u8]>,
+        W: fmt::Write + ?Sized,
+    {
+        let (old, new) = (old.as_ref(), new.as_ref());
+        let len = old.len().max(new.len());
+
+        let mut start = 0;
+        while start < len {
+            let end = start.saturating_add(self.columns);
+            let old_line = old.get(start..end.min(old.len())).unwrap_or_default();
+            let new_line = new.get(start..end.min(new.len())).unwrap_or_default();
+
+            if old_line != new_line {
+                if self.offsets != OffsetFormat::Hidden {
+                    self.write_offset(w, self.start.wrapping_add(start as u64))?;
+                    w.write_str(match self.style {
+                        Style::Xxd => ": ",
+                        Style::Canonical => "  ",
+                    })?;
+                }
+
+                self.write_bytes(w, old_line)?;
+                for _ in old_line.len()..self.columns {
+                    w.write_char(' ')?;
+                }
+                w.write_str(" |")?;
+                if !new_line.is_empty() {
+                    w.write_char(' ')?;
+                    self.write_bytes(w, new_line)?;
+                }
+                w.write_char('\n')?;
+            }
+
+            start = end;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_diff() {
+        let dumper = Dumper::new().columns(4).offsets(OffsetFormat::Hidden);
+        assert_eq!(dumper.diff(b"kiwi", b"kiwi"), "");
+        assert_eq!(dumper.diff([], []), "");
+        assert_eq!(
+            dumper.diff(b"kiwi fruit", b"kiwi Fruit"),
+            "2066 7275   fru | 2046 7275   Fru\n"
+        );
+        assert_eq!(dumper.diff(b"kiwi", b""), "6b69 7769  kiwi |\n");
+        assert_eq!(
+            dumper.diff(b"", b"kiwi"),
+            "                | 6b69 7769  kiwi\n"
+        );
+    }
+
+    #[test]
+    fn test_diff_canonical() {
+        let dumper = Dumper::canonical().columns(4).group_size(2).start(0x100);
+        assert_eq!(
+            dumper.diff(b"kiwi", b"kiWi"),
+            "00000100  6b 69  77 69  |kiwi| | 6b 69  57 69  |kiWi|\n"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Line by line hexdumps without allocation.
+use core::{fmt, slice, str};
+
+use super::{Dumper, Style};
+
+/// A single line of a hexdump, stored in a buffer of `N` bytes.
+///
+/// The line includes its terminating line feed.
+#[derive(Clone)]
+pub struct Line<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Line<N> {
+    const fn new() -> Line<N> {
+        Line {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the line as string slice.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the buffer is only written by `write_str`, which copies
+        // whole string slices.
+        unsafe { str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    /// Returns the line as bytes slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl<const N: usize> fmt::Write for Line<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let buf = self
+            .buf
+            .get_mut(self.len..self.len + s.len())
+            .ok_or(fmt::Error)?;
+        buf.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+impl<const N: usize> fmt::Display for Line<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Debug for Line<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Line").field(&self.as_str()).finish()
+    }
+}
+
+/// An iterator over the lines of a hexdump.
+///
+/// This `struct` is created by [`Dumper::lines`].
+#[derive(Debug, Clone)]
+pub struct Lines<'a, const N: usize> {
+    dumper: Dumper,
+    chunks: slice::Chunks<'a, u8>,
+    offset: u64,
+    // whether the line with the end offset is still to be written
+    trailer: bool,
+}
+
+impl<'a, const N: usize> Lines<'a, N> {
+    pub(super) fn new(dumper: &Dumper, data: &'a [u8]) -> Lines<'a, N> {
+        Lines {
+            dumper: *dumper,
+            chunks: data.chunks(dumper.columns),
+            offset: dumper.start,
+            trailer: dumper.style == Style::Canonical && !data.is_empty(),
+        }
+    }
+}
+
+impl<const N: usize> Iterator for Lines<'_, N> {
+    type Item = Line<N>;
+
+    fn next(&mut self) -> Option<Line<N>> {
+        let mut line = Line::new();
+
+        let result = match self.chunks.next() {
+            Some(chunk) => {
+                let result = self.dumper.write_line(&mut line, self.offset, chunk);
+                self.offset = self.offset.wrapping_add(chunk.len() as u64);
+                result
+            }
+            None if self.trailer => {
+                self.trailer = false;
+                self.dumper
+                    .write_offset(&mut line, self.offset)
+                    .and_then(|_| fmt::Write::write_char(&mut line, '\n'))
+            }
+            None => return None,
+        };
+
+        assert!(
+            result.is_ok(),
+            "line of the dump doesn't fit into a buffer of {} bytes",
+            N
+        );
+        Some(line)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.chunks.len() + self.trailer as usize;
+        (len, Some(len))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for Lines<'_, N> {}
+
+impl<const N: usize> core::iter::FusedIterator for Lines<'_, N> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{dump::OffsetFormat, Case};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_lines() {
+        const DUMPER: Dumper = Dumper::new();
+        const N: usize = DUMPER.max_line_len();
+
+        let mut lines = DUMPER.lines::<N>(b"Hello, world! this is longer text");
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines.next().unwrap().as_str(),
+            "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 2120 7468  Hello, world! th\n"
+        );
+        assert_eq!(
+            lines.nth(1).unwrap().as_bytes(),
+            b"00000020: 74                                       t\n"
+        );
+        assert!(lines.next().is_none());
+
+        assert_eq!(DUMPER.lines::<N>(&[]).count(), 0);
+    }
+
+    #[test]
+    fn test_lines_canonical() {
+        let dumper = Dumper::canonical().start(0x10);
+        let mut lines = dumper.lines::<80>(b"kiwi");
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines.next().unwrap().as_str(),
+            "00000010  6b 69 77 69                                       |kiwi|\n"
+        );
+        assert_eq!(lines.next().unwrap().as_str(), "00000014\n");
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn test_max_line_len() {
+        let data = [0x00, 0x0a, 0x41, 0xff, 0x00, 0xff, 0x41];
+        let dumpers = [
+            Dumper::new().start(u64::MAX - 1),
+            Dumper::new().columns(7).group_size(3).color(true),
+            Dumper::canonical()
+                .offsets(OffsetFormat::Decimal)
+                .start(u64::MAX)
+                .color(true),
+            Dumper::canonical()
+                .offsets(OffsetFormat::Hidden)
+                .case(Case::Upper),
+        ];
+
+        for dumper in dumpers {
+            for line in dumper.lines::<256>(&data) {
+                assert!(line.as_str().len() <= dumper.max_line_len());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "line of the dump doesn't fit into a buffer of 40 bytes")]
+    fn test_lines_too_long() {
+        let _ = Dumper::new().lines::<40>(b"kiwi").next();
+    }
+}
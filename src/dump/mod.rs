@@ -0,0 +1,660 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Hexdumps.
+//!
+//! A hexdump shows binary data line by line: every line starts with the
+//! offset of its first byte, followed by the bytes as hex digits and a gutter
+//! with the printable ASCII characters among them.
+//!
+//! [`dump()`] produces the output of `xxd`, a [`Dumper`] allows changing the
+//! layout and writing the dump into a [`fmt::Write`] or `std::io::Write` sink
+//! instead. `parse()` turns a dump back into the dumped bytes.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! assert_eq!(
+//!     hex::dump::dump(b"Hello, world! Hello, hex!"),
+//!     "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 2120 4865  Hello, world! He\n\
+//!      00000010: 6c6c 6f2c 2068 6578 21                   llo, hex!\n"
+//! );
+//! ```
+#[cfg(feature = "alloc")]
+mod diff;
+mod lines;
+#[cfg(feature = "alloc")]
+mod parse;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::{byte2hex, Case};
+
+pub use self::lines::{Line, Lines};
+#[cfg(feature = "alloc")]
+pub use self::parse::{parse, parse_with, Dialect};
+
+/// The format of the offset column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OffsetFormat {
+    /// Offsets are written as 8 hex digits (e.g. `000001a0`).
+    Hex,
+    /// Offsets are written as 8 decimal digits (e.g. `00000416`).
+    Decimal,
+    /// The offset column is left out.
+    Hidden,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    // `00000000: 4865 6c6c  He..`
+    Xxd,
+    // `00000000  48 65 6c 6c  |He..|`, followed by a line with the end offset.
+    Canonical,
+}
+
+/// A configurable hexdump writer.
+///
+/// By default the output matches `xxd`: 16 bytes per line in groups of 2,
+/// hex offsets and lowercase digits. [`Dumper::canonical`] matches the
+/// canonical format of `hexdump -C` instead, where every byte is separated
+/// by a space and the groups by an additional space.
+///
+/// Offsets are always written in lowercase.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// use hex::{dump::{Dumper, OffsetFormat}, Case};
+///
+/// let dumper = Dumper::new()
+///     .columns(8)
+///     .group_size(4)
+///     .case(Case::Upper)
+///     .offsets(OffsetFormat::Decimal)
+///     .start(1000);
+///
+/// assert_eq!(
+///     dumper.dump(b"\xfeHello, world!"),
+///     "00001000: FE48656C 6C6F2C20  .Hello, \n\
+///      00001008: 776F726C 6421      world!\n"
+/// );
+///
+/// assert_eq!(
+///     Dumper::canonical().dump(b"Hello, world!\n"),
+///     "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a        |Hello, world!.|\n\
+///      0000000e\n"
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dumper {
+    style: Style,
+    columns: usize,
+    group_size: usize,
+    case: Case,
+    offsets: OffsetFormat,
+    start: u64,
+    color: bool,
+}
+
+impl Dumper {
+    /// Creates a dumper producing the output of `xxd`.
+    pub const fn new() -> Dumper {
+        Dumper {
+            style: Style::Xxd,
+            columns: 16,
+            group_size: 2,
+            case: Case::Lower,
+            offsets: OffsetFormat::Hex,
+            start: 0,
+            color: false,
+        }
+    }
+
+    /// Creates a dumper producing the canonical output of `hexdump -C`.
+    ///
+    /// Unlike `hexdump`, repeated lines aren't collapsed into a `*`.
+    pub const fn canonical() -> Dumper {
+        Dumper {
+            style: Style::Canonical,
+            group_size: 8,
+            ..Dumper::new()
+        }
+    }
+
+    /// Sets the number of bytes shown on a single line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is zero.
+    #[must_use]
+    pub const fn columns(mut self, columns: usize) -> Dumper {
+        assert!(columns > 0, "number of columns must not be zero");
+        self.columns = columns;
+        self
+    }
+
+    /// Sets the number of bytes in a group of hex digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is zero.
+    #[must_use]
+    pub const fn group_size(mut self, bytes: usize) -> Dumper {
+        assert!(bytes > 0, "group size must not be zero");
+        self.group_size = bytes;
+        self
+    }
+
+    /// Sets the letter casing of the hex digits.
+    #[must_use]
+    pub const fn case(mut self, case: Case) -> Dumper {
+        self.case = case;
+        self
+    }
+
+    /// Sets the format of the offset column.
+    #[must_use]
+    pub const fn offsets(mut self, offsets: OffsetFormat) -> Dumper {
+        self.offsets = offsets;
+        self
+    }
+
+    /// Sets the offset of the first byte, e.g. its address in memory or
+    /// position within a file.
+    #[must_use]
+    pub const fn start(mut self, offset: u64) -> Dumper {
+        self.start = offset;
+        self
+    }
+
+    /// Sets whether the bytes are colored using ANSI escape codes, according
+    /// to their kind:
+    ///
+    /// - NUL bytes are dimmed,
+    /// - printable ASCII characters are green,
+    /// - other ASCII characters (whitespace and control characters) are
+    ///   magenta,
+    /// - non-ASCII bytes are yellow.
+    ///
+    /// Both the hex digits and the ASCII gutter are colored. This should only
+    /// be enabled when writing to a terminal.
+    ///
+    /// ```
+    /// use hex::dump::{Dumper, OffsetFormat};
+    ///
+    /// let dumper = Dumper::new().columns(2).offsets(OffsetFormat::Hidden).color(true);
+    ///
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(
+    ///     dumper.dump(b"a\xff"),
+    ///     "\x1b[32m61\x1b[33mff\x1b[0m  \x1b[32ma\x1b[33m.\x1b[0m\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn color(mut self, color: bool) -> Dumper {
+        self.color = color;
+        self
+    }
+
+    /// Dumps `data` into a newly allocated `String`.
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn dump<T: AsRef<[u8]>>(&self, data: T) -> String {
+        let data = data.as_ref();
+        let mut buf = String::new();
+        // the hint is only a guess, a dump which is too large fails below
+        let _ = buf.try_reserve(self.capacity_hint(data.len()));
+
+        // writing into a `String` never fails
+        let _ = self.dump_to_fmt(data, &mut buf);
+        buf
+    }
+
+    // roughly the length of the dump of `len` bytes, saturating instead of
+    // overflowing for large column counts.
+    #[cfg(feature = "alloc")]
+    fn capacity_hint(&self, len: usize) -> usize {
+        let lines = len / self.columns + 1;
+        lines.saturating_mul(self.columns.saturating_mul(4).saturating_add(16))
+    }
+
+    /// Writes the dump of `data` into a [`fmt::Write`] sink, without
+    /// allocating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::fmt::Write;
+    /// use hex::dump::Dumper;
+    ///
+    /// struct Uart;
+    ///
+    /// impl Write for Uart {
+    ///     fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    ///         // send `s` over the wire
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// Dumper::new().dump_to_fmt(b"kiwi", &mut Uart)?;
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    pub fn dump_to_fmt<T, W>(&self, data: T, w: &mut W) -> fmt::Result
+    where
+        T: AsRef<[u8]>,
+        W: fmt::Write + ?Sized,
+    {
+        let data = data.as_ref();
+        let mut offset = self.start;
+        for line in data.chunks(self.columns) {
+            self.write_line(w, offset, line)?;
+            offset = offset.wrapping_add(line.len() as u64);
+        }
+
+        if self.style == Style::Canonical && !data.is_empty() {
+            self.write_offset(w, offset)?;
+            w.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator over the lines of the dump of `data`, each stored
+    /// in a buffer of `N` bytes.
+    ///
+    /// This allows producing a dump piece by piece without allocating, e.g.
+    /// to send it line by line over a serial connection.
+    /// [`max_line_len`](Self::max_line_len) returns a buffer size which is
+    /// large enough for every line.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if a line doesn't fit into `N` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::dump::Dumper;
+    ///
+    /// const DUMPER: Dumper = Dumper::new().columns(4);
+    /// const LINE_LEN: usize = DUMPER.max_line_len();
+    ///
+    /// let mut lines = DUMPER.lines::<LINE_LEN>(b"kiwi fruit");
+    /// assert_eq!(lines.next().unwrap().as_str(), "00000000: 6b69 7769  kiwi\n");
+    /// assert_eq!(lines.next().unwrap().as_str(), "00000004: 2066 7275   fru\n");
+    /// assert_eq!(lines.next().unwrap().as_str(), "00000008: 6974       it\n");
+    /// assert!(lines.next().is_none());
+    /// ```
+    pub fn lines<'a, const N: usize>(&self, data: &'a [u8]) -> Lines<'a, N> {
+        Lines::new(self, data)
+    }
+
+    /// Returns the maximum length of a single line of the dump, including
+    /// the line feed.
+    ///
+    /// The length is an upper bound for any data, assuming the largest
+    /// possible offsets and, with [`color`](Self::color) enabled, a change of
+    /// the color for every byte.
+    pub const fn max_line_len(&self) -> usize {
+        let offset = match self.offsets {
+            OffsetFormat::Hex => 16 + 2,
+            OffsetFormat::Decimal => 20 + 2,
+            OffsetFormat::Hidden => 0,
+        };
+        let groups = (self.columns + self.group_size - 1) / self.group_size;
+        let (spaces, gutter) = match self.style {
+            Style::Xxd => (groups - 1, 2),
+            Style::Canonical => (self.columns - 1 + groups - 1, 4),
+        };
+        // every byte may switch colors, and both the digits and the gutter
+        // are reset at their end
+        let colors = if self.color {
+            2 * (self.columns * (Color::RESET.len() + Color::DIM.len()) + Color::RESET.len())
+        } else {
+            0
+        };
+
+        offset + self.columns * 2 + spaces + gutter + self.columns + colors + 1
+    }
+
+    /// Writes the dump of `data` into an [`io::Write`] sink, e.g. a file or
+    /// standard output.
+    ///
+    /// The output is written in chunks of a few kilobytes, so the writer
+    /// doesn't need to be buffered. It isn't flushed, though.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::dump::Dumper;
+    ///
+    /// let mut out = Vec::new();
+    /// Dumper::new().dump_to_writer(b"kiwi", &mut out)?;
+    /// assert_eq!(out, b"00000000: 6b69 7769                                kiwi\n");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn dump_to_writer<T, W>(&self, data: T, w: &mut W) -> io::Result<()>
+    where
+        T: AsRef<[u8]>,
+        W: io::Write + ?Sized,
+    {
+        let mut adapter = IoAdapter {
+            inner: w,
+            buf: [0; 4096],
+            len: 0,
+            error: None,
+        };
+
+        let result = self.dump_to_fmt(data, &mut adapter).and_then(|_| {
+            let len = adapter.len;
+            adapter.write_buf(len)
+        });
+        match (result, adapter.error) {
+            (Ok(()), _) => Ok(()),
+            (Err(_), Some(err)) => Err(err),
+            (Err(_), None) => Err(io::Error::new(io::ErrorKind::Other, "formatter error")),
+        }
+    }
+
+    fn write_offset<W: fmt::Write + ?Sized>(&self, w: &mut W, offset: u64) -> fmt::Result {
+        match self.offsets {
+            OffsetFormat::Hex => write!(w, "{:08x}", offset),
+            OffsetFormat::Decimal => write!(w, "{:08}", offset),
+            OffsetFormat::Hidden => Ok(()),
+        }
+    }
+
+    fn write_line<W: fmt::Write + ?Sized>(
+        &self,
+        w: &mut W,
+        offset: u64,
+        line: &[u8],
+    ) -> fmt::Result {
+        if self.offsets != OffsetFormat::Hidden {
+            self.write_offset(w, offset)?;
+            w.write_str(match self.style {
+                Style::Xxd => ": ",
+                Style::Canonical => "  ",
+            })?;
+        }
+
+        self.write_bytes(w, line)?;
+        w.write_char('\n')
+    }
+
+    // writes the hex digits and the gutter of a line.
+    fn write_bytes<W: fmt::Write + ?Sized>(&self, w: &mut W, line: &[u8]) -> fmt::Result {
+        let table = self.case.table();
+
+        let mut color = Color::new(self.color);
+        for i in 0..self.columns {
+            if i > 0 && self.style == Style::Canonical {
+                w.write_char(' ')?;
+            }
+            if i > 0 && i % self.group_size == 0 {
+                w.write_char(' ')?;
+            }
+            match line.get(i) {
+                Some(&byte) => {
+                    let (high, low) = byte2hex(byte, table);
+                    color.set(w, byte)?;
+                    w.write_char(high as char)?;
+                    w.write_char(low as char)?;
+                }
+                None => {
+                    color.reset(w)?;
+                    w.write_str("  ")?;
+                }
+            }
+        }
+        color.reset(w)?;
+
+        w.write_str(match self.style {
+            Style::Xxd => "  ",
+            Style::Canonical => "  |",
+        })?;
+        for &byte in line {
+            color.set(w, byte)?;
+            w.write_char(if is_printable(byte) {
+                byte as char
+            } else {
+                '.'
+            })?;
+        }
+        color.reset(w)?;
+        if self.style == Style::Canonical {
+            w.write_char('|')?;
+        }
+        Ok(())
+    }
+}
+
+// buffers the output of a dump for an `io::Write` sink.
+#[cfg(feature = "std")]
+struct IoAdapter<'a, W: ?Sized> {
+    inner: &'a mut W,
+    buf: [u8; 4096],
+    len: usize,
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write + ?Sized> IoAdapter<'_, W> {
+    fn write_buf(&mut self, len: usize) -> fmt::Result {
+        self.len = 0;
+        self.inner.write_all(&self.buf[..len]).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write + ?Sized> fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.len + s.len() > self.buf.len() {
+            self.write_buf(self.len)?;
+        }
+        if s.len() > self.buf.len() {
+            return self.inner.write_all(s.as_bytes()).map_err(|err| {
+                self.error = Some(err);
+                fmt::Error
+            });
+        }
+
+        self.buf[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+impl Default for Dumper {
+    fn default() -> Self {
+        Dumper::new()
+    }
+}
+
+const fn is_printable(byte: u8) -> bool {
+    matches!(byte, b' '..=b'~')
+}
+
+// tracks the current ANSI color, to only write escape codes when the color
+// changes.
+struct Color {
+    enabled: bool,
+    current: Option<&'static str>,
+}
+
+impl Color {
+    const RESET: &'static str = "\x1b[0m";
+    const DIM: &'static str = "\x1b[2m";
+
+    const fn new(enabled: bool) -> Color {
+        Color {
+            enabled,
+            current: None,
+        }
+    }
+
+    fn set<W: fmt::Write + ?Sized>(&mut self, w: &mut W, byte: u8) -> fmt::Result {
+        let code = match byte {
+            0x00 => Color::DIM,
+            b' '..=b'~' => "\x1b[32m",
+            0x01..=0x7f => "\x1b[35m",
+            _ => "\x1b[33m",
+        };
+
+        if self.enabled && self.current != Some(code) {
+            // leave dimmed mode, which isn't reset by a color
+            if self.current == Some(Color::DIM) {
+                w.write_str(Color::RESET)?;
+            }
+            w.write_str(code)?;
+            self.current = Some(code);
+        }
+        Ok(())
+    }
+
+    fn reset<W: fmt::Write + ?Sized>(&mut self, w: &mut W) -> fmt::Result {
+        if self.current.take().is_some() {
+            w.write_str(Color::RESET)?;
+        }
+        Ok(())
+    }
+}
+
+/// Dumps `data` in the format of `xxd`.
+///
+/// Every line shows 16 bytes in groups of 2, and ends with a line feed. An
+/// empty input results in an empty dump. See [`Dumper`] for other layouts.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(
+///     hex::dump::dump(b"kiwi\n"),
+///     "00000000: 6b69 7769 0a                             kiwi.\n"
+/// );
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn dump<T: AsRef<[u8]>>(data: T) -> String {
+    Dumper::new().dump(data)
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_dump() {
+        assert_eq!(dump([]), "");
+        assert_eq!(
+            dump(b"Hello, world! this is longer text"),
+            "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 2120 7468  Hello, world! th\n\
+             00000010: 6973 2069 7320 6c6f 6e67 6572 2074 6578  is is longer tex\n\
+             00000020: 74                                       t\n"
+        );
+        assert_eq!(
+            dump([0x00, 0x1f, 0x7f, 0xff, b'~']),
+            "00000000: 001f 7fff 7e                             ....~\n"
+        );
+    }
+
+    #[test]
+    fn test_dumper_layout() {
+        // `xxd -u -g 1 -c 4 -o 171`
+        let dumper = Dumper::new()
+            .columns(4)
+            .group_size(1)
+            .case(Case::Upper)
+            .start(171);
+        assert_eq!(
+            dumper.dump(b"\xfakiwi"),
+            "000000ab: FA 6B 69 77  .kiw\n\
+             000000af: 69           i\n"
+        );
+
+        let dumper = Dumper::new()
+            .columns(3)
+            .group_size(2)
+            .offsets(OffsetFormat::Hidden);
+        assert_eq!(dumper.dump(b"kiwi"), "6b69 77  kiw\n69       i\n");
+    }
+
+    #[test]
+    fn test_dumper_canonical() {
+        assert_eq!(Dumper::canonical().dump([]), "");
+        assert_eq!(
+            Dumper::canonical().dump(b"Hello, world! this is longer text"),
+            "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 20 74 68  |Hello, world! th|\n\
+             00000010  69 73 20 69 73 20 6c 6f  6e 67 65 72 20 74 65 78  |is is longer tex|\n\
+             00000020  74                                                |t|\n\
+             00000021\n"
+        );
+    }
+
+    #[test]
+    fn test_dumper_color() {
+        let dumper = Dumper::new()
+            .columns(4)
+            .offsets(OffsetFormat::Hidden)
+            .color(true);
+        assert_eq!(
+            dumper.dump(b"\0\0\n"),
+            "\x1b[2m0000 \x1b[0m\x1b[35m0a\x1b[0m    \
+             \x1b[2m..\x1b[0m\x1b[35m.\x1b[0m\n"
+        );
+        assert_eq!(Dumper::new().color(true).dump([]), "");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_dump_to_writer() {
+        use alloc::vec::Vec;
+
+        let data: Vec<u8> = (0..=255).cycle().take(10_000).collect();
+        let dumper = Dumper::canonical().color(true);
+
+        let mut out = Vec::new();
+        dumper.dump_to_writer(&data, &mut out).unwrap();
+        assert_eq!(out, dumper.dump(&data).as_bytes());
+
+        let mut full = [0; 100];
+        let err = dumper
+            .dump_to_writer(&data, &mut &mut full[..])
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    }
+
+    #[test]
+    fn test_dumper_capacity_hint() {
+        assert_eq!(Dumper::new().capacity_hint(0), 80);
+        assert_eq!(Dumper::new().capacity_hint(33), 240);
+        assert_eq!(
+            Dumper::new().columns(usize::MAX).capacity_hint(2),
+            usize::MAX
+        );
+        assert_eq!(
+            Dumper::new().columns(usize::MAX / 8).capacity_hint(2),
+            usize::MAX / 8 * 4 + 16
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "number of columns must not be zero")]
+    fn test_dumper_zero_columns() {
+        let _ = Dumper::new().columns(0);
+    }
+}
@@ -0,0 +1,519 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Parsing hexdumps back into bytes.
+use alloc::vec::Vec;
+
+use core::ops::Range;
+
+use crate::{val, FromHexError};
+
+/// The format of a hexdump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    /// The output of `xxd`: `00000000: 4865 6c6c 6f  Hello`.
+    Xxd,
+    /// The canonical output of `hexdump -C`:
+    /// `00000000  48 65 6c 6c 6f  |Hello|`.
+    Canonical,
+    /// The output of `od -A x -t x1z`: `000000 48 65 6c 6c 6f  >Hello<`.
+    /// The ASCII gutter is optional.
+    Od,
+    /// Wireshark's "Copy as Hex Dump": `0000   48 65 6c 6c 6f   Hello`.
+    Wireshark,
+    /// Wireshark's "Copy as a Hex Stream": `48656c6c6f`, a plain run of
+    /// digits without offsets. Whitespace is ignored.
+    HexStream,
+}
+
+impl Dialect {
+    /// Detects the dialect of a hexdump from its first non-empty line.
+    ///
+    /// Returns `None` if the text is empty or the line doesn't look like a
+    /// hexdump.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::dump::Dialect;
+    ///
+    /// assert_eq!(Dialect::detect("00000000: 6b69 7769  kiwi"), Some(Dialect::Xxd));
+    /// assert_eq!(Dialect::detect("00000000  6b 69 77 69  |kiwi|"), Some(Dialect::Canonical));
+    /// assert_eq!(Dialect::detect("000000 6b 69 77 69"), Some(Dialect::Od));
+    /// assert_eq!(Dialect::detect("0000   6b 69 77 69   kiwi"), Some(Dialect::Wireshark));
+    /// assert_eq!(Dialect::detect("6b697769"), Some(Dialect::HexStream));
+    /// ```
+    pub fn detect<T: AsRef<[u8]>>(text: T) -> Option<Dialect> {
+        let line = text
+            .as_ref()
+            .split(|&c| c == b'\n')
+            .map(trim)
+            .find(|line| !line.is_empty())?;
+
+        let token_len = line
+            .iter()
+            .position(u8::is_ascii_whitespace)
+            .unwrap_or(line.len());
+        let (token, rest) = line.split_at(token_len);
+        let spaces = rest.iter().take_while(|c| c.is_ascii_whitespace()).count();
+
+        if token.contains(&b':') {
+            Some(Dialect::Xxd)
+        } else if !token.iter().all(u8::is_ascii_hexdigit) {
+            None
+        } else if rest.is_empty() {
+            Some(Dialect::HexStream)
+        } else if spaces == 1 {
+            Some(Dialect::Od)
+        } else if spaces == 2 && line.ends_with(b"|") && rest.windows(3).any(|w| w == b"  |") {
+            // a `|` may appear in the gutter of other dialects too, so only
+            // a `|...|` gutter after the offset spacing of `hexdump -C` counts
+            Some(Dialect::Canonical)
+        } else {
+            Some(Dialect::Wireshark)
+        }
+    }
+
+    // whether the ASCII gutter starts at `line[i]`.
+    fn is_gutter(self, line: &[u8], i: usize) -> bool {
+        let spaces = |n| line[i..].iter().take(n).all(|&c| c == b' ') && line.len() >= i + n;
+        match self {
+            Dialect::Xxd => spaces(2),
+            Dialect::Canonical => line[i] == b'|',
+            Dialect::Od => line[i] == b'>',
+            Dialect::Wireshark => spaces(3),
+            Dialect::HexStream => false,
+        }
+    }
+}
+
+/// Parses a hexdump back into the dumped bytes, like `xxd -r` does.
+///
+/// The [`Dialect`] of the dump is detected automatically, see
+/// [`parse_with`] for details.
+///
+/// # Example
+///
+/// ```
+/// let dump = "\
+///     00000000: 4865 6c6c 6f2c 2077 6f72 6c64 2120 4865  Hello, world! He\n\
+///     00000010: 6c6c 6f2c 2068 6578 21                   llo, hex!\n";
+///
+/// assert_eq!(hex::dump::parse(dump)?, b"Hello, world! Hello, hex!");
+///
+/// let dump = "\
+///     00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a        |Hello, world!.|\n\
+///     0000000e\n";
+///
+/// assert_eq!(hex::dump::parse(dump)?, b"Hello, world!\n");
+///
+/// // gaps between the lines are filled with zeros
+/// assert_eq!(hex::dump::parse("00000004: 6b69  ki\n")?, b"\0\0\0\0ki");
+/// # Ok::<(), hex::FromHexError>(())
+/// ```
+pub fn parse<T: AsRef<[u8]>>(text: T) -> Result<Vec<u8>, FromHexError> {
+    let text = text.as_ref();
+    parse_with(text, Dialect::detect(text).unwrap_or(Dialect::Xxd))
+}
+
+/// Parses a hexdump of the given [`Dialect`] back into the dumped bytes.
+///
+/// Every line consists of an offset, the hex digits and an optional ASCII
+/// gutter, which is ignored. The bytes of each line are placed at the line's
+/// offset, so sparse dumps are reconstructed with the gaps filled by zeros,
+/// and the output always starts at offset zero. Empty lines are skipped.
+///
+/// A line consisting of a `*` marks repetitions of the previous line, up to
+/// the offset of the next line. A line consisting of an offset only marks the
+/// end of the dump.
+///
+/// A gap, whether filled with zeros or repetitions, may be at most as long as
+/// `text` itself, so that a few offsets can't make up an arbitrarily large
+/// output.
+///
+/// # Errors
+///
+/// Returns [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) with
+/// the position within `text` if an offset or a digit is invalid,
+/// [`OddLength`](FromHexError::OddLength) if a byte is missing a digit, and
+/// [`InvalidStringLength`](FromHexError::InvalidStringLength) if a line has
+/// no offset, its offset is out of range or leaves a gap which is too long.
+///
+/// # Example
+///
+/// ```
+/// use hex::dump::{parse_with, Dialect};
+///
+/// let dump = "\
+///     000000 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00\n\
+///     *\n\
+///     000020 6b 69 77 69\n\
+///     000024\n";
+///
+/// let mut data = vec![0; 32];
+/// data.extend_from_slice(b"kiwi");
+/// assert_eq!(parse_with(dump, Dialect::Od)?, data);
+/// # Ok::<(), hex::FromHexError>(())
+/// ```
+pub fn parse_with<T: AsRef<[u8]>>(text: T, dialect: Dialect) -> Result<Vec<u8>, FromHexError> {
+    let text = text.as_ref();
+    let max_gap = text.len();
+    let mut out = Vec::new();
+
+    if dialect == Dialect::HexStream {
+        let mut digits = Digits::new(0, max_gap);
+        for (i, &c) in text.iter().enumerate() {
+            if !c.is_ascii_whitespace() {
+                digits.push(&mut out, c, i)?;
+            }
+        }
+        digits.finish()?;
+        return Ok(out);
+    }
+
+    // the bytes of the previous line, repeated by a `*` line
+    let mut previous = 0..0;
+    let mut repeat = false;
+
+    let mut start = 0;
+    for line in text.split(|&c| c == b'\n') {
+        let index = start;
+        start += line.len() + 1;
+
+        let line = trim(line);
+        if line.is_empty() {
+            continue;
+        }
+        if line == b"*" {
+            repeat = true;
+            continue;
+        }
+
+        let (offset, digits_start) = parse_offset(line, index, dialect)?;
+        if repeat {
+            repeat_range(&mut out, previous.clone(), offset, max_gap)?;
+            repeat = false;
+        }
+
+        let mut digits = Digits::new(offset, max_gap);
+        let line = &line[digits_start..];
+        for (i, &c) in line.iter().enumerate() {
+            if dialect.is_gutter(line, i) {
+                break;
+            }
+            if c == b' ' || c == b'\t' {
+                digits.separate()?;
+            } else {
+                digits.push(&mut out, c, index + digits_start + i)?;
+            }
+        }
+        digits.finish()?;
+
+        if digits.offset > offset {
+            previous = offset..digits.offset;
+        } else {
+            // the end offset of the dump
+            resize(&mut out, offset, max_gap)?;
+        }
+    }
+
+    Ok(out)
+}
+
+// removes the line ending and trailing whitespace.
+fn trim(line: &[u8]) -> &[u8] {
+    let len = line
+        .iter()
+        .rposition(|c| !c.is_ascii_whitespace())
+        .map_or(0, |i| i + 1);
+    &line[..len]
+}
+
+// parses the offset at the start of `line`, returning it along with the
+// position of the following digits.
+fn parse_offset(
+    line: &[u8],
+    index: usize,
+    dialect: Dialect,
+) -> Result<(usize, usize), FromHexError> {
+    let end = match dialect {
+        Dialect::Xxd => line.iter().position(|&c| c == b':'),
+        _ => Some(
+            line.iter()
+                .position(u8::is_ascii_whitespace)
+                .unwrap_or(line.len()),
+        ),
+    };
+    let end = match end {
+        Some(end) if end > 0 => end,
+        _ => return Err(FromHexError::InvalidStringLength),
+    };
+
+    let mut offset: usize = 0;
+    for (i, &c) in line[..end].iter().enumerate() {
+        let digit = val(c, index + i)?;
+        offset = offset
+            .checked_mul(16)
+            .and_then(|offset| offset.checked_add(digit as usize))
+            .ok_or(FromHexError::InvalidStringLength)?;
+    }
+
+    let separator = match dialect {
+        Dialect::Xxd => end + 1,
+        _ => end,
+    };
+    let digits_start = separator
+        + line[separator..]
+            .iter()
+            .take_while(|c| c.is_ascii_whitespace())
+            .count();
+    Ok((offset, digits_start))
+}
+
+// grows `out` to `len` bytes by padding it with zeros. Offsets come from
+// the input, so the padding is limited to `max_gap` bytes, and a failed
+// allocation is reported instead of aborting.
+fn resize(out: &mut Vec<u8>, len: usize, max_gap: usize) -> Result<(), FromHexError> {
+    if let Some(additional) = len.checked_sub(out.len()) {
+        if additional > max_gap {
+            return Err(FromHexError::InvalidStringLength);
+        }
+        out.try_reserve(additional)
+            .map_err(|_| FromHexError::InvalidStringLength)?;
+        out.resize(len, 0);
+    }
+    Ok(())
+}
+
+// fills `out` up to `end` by repeating the bytes in `range`, at most
+// `max_gap` bytes.
+fn repeat_range(
+    out: &mut Vec<u8>,
+    range: Range<usize>,
+    end: usize,
+    max_gap: usize,
+) -> Result<(), FromHexError> {
+    if range.is_empty() {
+        return Ok(());
+    }
+    if let Some(additional) = end.checked_sub(out.len()) {
+        if additional > max_gap {
+            return Err(FromHexError::InvalidStringLength);
+        }
+        out.try_reserve(additional)
+            .map_err(|_| FromHexError::InvalidStringLength)?;
+    }
+
+    let mut i = range.start;
+    while out.len() < end {
+        out.push(out[i]);
+        i = if i + 1 == range.end {
+            range.start
+        } else {
+            i + 1
+        };
+    }
+    Ok(())
+}
+
+// decodes the digits of a line into `out`, starting at `offset`.
+struct Digits {
+    offset: usize,
+    max_gap: usize,
+    high: Option<u8>,
+}
+
+impl Digits {
+    const fn new(offset: usize, max_gap: usize) -> Digits {
+        Digits {
+            offset,
+            max_gap,
+            high: None,
+        }
+    }
+
+    fn push(&mut self, out: &mut Vec<u8>, c: u8, index: usize) -> Result<(), FromHexError> {
+        let digit = val(c, index)?;
+        match self.high.take() {
+            None => self.high = Some(digit),
+            Some(high) => {
+                let byte = high << 4 | digit;
+                resize(out, self.offset, self.max_gap)?;
+                match out.get_mut(self.offset) {
+                    Some(slot) => *slot = byte,
+                    None => out.push(byte),
+                }
+                self.offset = self
+                    .offset
+                    .checked_add(1)
+                    .ok_or(FromHexError::InvalidStringLength)?;
+            }
+        }
+        Ok(())
+    }
+
+    // a byte mustn't be split by whitespace.
+    fn separate(&self) -> Result<(), FromHexError> {
+        match self.high {
+            Some(_) => Err(FromHexError::OddLength),
+            None => Ok(()),
+        }
+    }
+
+    fn finish(&self) -> Result<(), FromHexError> {
+        self.separate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::dump::{dump, Dumper};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_roundtrip() {
+        let data: Vec<u8> = (0..=255).collect();
+        assert_eq!(parse(dump(&data)).unwrap(), data);
+        assert_eq!(
+            parse(Dumper::new().columns(7).group_size(3).dump(&data)).unwrap(),
+            data
+        );
+        assert_eq!(parse(Dumper::canonical().dump(&data)).unwrap(), data);
+        assert_eq!(parse(dump([])).unwrap(), []);
+    }
+
+    #[test]
+    fn test_parse_offsets() {
+        let dump = "00000002: 0102  ..\r\n\n00000000: 0a0b  ..\r\n";
+        assert_eq!(parse(dump).unwrap(), [0x0a, 0x0b, 0x01, 0x02]);
+    }
+
+    #[test]
+    fn test_parse_gutter() {
+        // hex digits in the gutter are ignored
+        assert_eq!(parse("00000000: 6361 6665  cafe").unwrap(), b"cafe");
+        assert_eq!(parse("00000000: 6361 66    caf").unwrap(), b"caf");
+        assert_eq!(
+            parse("00000000  63 61 66 65  |cafe|\n00000004").unwrap(),
+            b"cafe"
+        );
+        assert_eq!(parse("000000 63 61 66 65  >cafe<").unwrap(), b"cafe");
+        assert_eq!(parse("0000  63 61  66 65   cafe").unwrap(), b"cafe");
+    }
+
+    #[test]
+    fn test_parse_od() {
+        let dump = "\
+            000000 48 65 6c 6c 6f 2c 20 77 6f 72 6c 64 21 20 74 68  >Hello, world! th<\n\
+            000010 69 73 20 69 73 20 6c 6f 6e 67 65 72 20 74 65 78  >is is longer tex<\n\
+            000020 74 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  >t...............<\n\
+            000030 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  >................<\n\
+            *\n\
+            000060 00 00 00 00 00 00 00 00 78                       >........x<\n\
+            000069\n";
+
+        let mut data = b"Hello, world! this is longer text".to_vec();
+        data.resize(0x68, 0);
+        data.push(b'x');
+        assert_eq!(Dialect::detect(dump), Some(Dialect::Od));
+        assert_eq!(parse(dump).unwrap(), data);
+    }
+
+    #[test]
+    fn test_parse_wireshark() {
+        let dump = "\
+            0000   48 65 6c 6c 6f 2c 20 77 6f 72 6c 64 21 0a 63 61   Hello, world!.ca\n\
+            0010   66 65                                             fe\n";
+        assert_eq!(Dialect::detect(dump), Some(Dialect::Wireshark));
+        assert_eq!(parse(dump).unwrap(), b"Hello, world!\ncafe");
+
+        let stream = "48656c6c6f2c20776f726c64210a\n";
+        assert_eq!(Dialect::detect(stream), Some(Dialect::HexStream));
+        assert_eq!(parse(stream).unwrap(), b"Hello, world!\n");
+        assert_eq!(
+            parse_with("48 65\n6c 6c 6f", Dialect::HexStream).unwrap(),
+            b"Hello"
+        );
+    }
+
+    #[test]
+    fn test_parse_repeat() {
+        let dump = "00000000  01 02 03  |...|\n*\n00000008\n";
+        assert_eq!(
+            parse(dump).unwrap(),
+            [0x01, 0x02, 0x03, 0x01, 0x02, 0x03, 0x01, 0x02]
+        );
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(Dialect::detect(""), None);
+        assert_eq!(Dialect::detect("\n  \n"), None);
+        assert_eq!(Dialect::detect("hello world"), None);
+        assert_eq!(Dialect::detect("\n0: 41\n"), Some(Dialect::Xxd));
+
+        // a `|` in the gutter doesn't make a canonical dump
+        let dump = "0000   41 42 7c   AB|";
+        assert_eq!(Dialect::detect(dump), Some(Dialect::Wireshark));
+        assert_eq!(parse(dump).unwrap(), b"AB|");
+        let dump = "000000 41 42 7c  >AB|<";
+        assert_eq!(Dialect::detect(dump), Some(Dialect::Od));
+        assert_eq!(parse(dump).unwrap(), b"AB|");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            parse("00000000: 4x  H"),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 11 })
+        );
+        assert_eq!(
+            parse("0000000g: 41  A"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 7 })
+        );
+        assert_eq!(
+            parse("00000000: 41\n00000001: 4 2"),
+            Err(FromHexError::OddLength)
+        );
+        assert_eq!(parse("414"), Err(FromHexError::OddLength));
+        assert_eq!(
+            parse_with("4142", Dialect::Xxd),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(parse(": 4142"), Err(FromHexError::InvalidStringLength));
+        assert_eq!(
+            parse("fffffffffffffffffffff: 41"),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn test_parse_huge_offset() {
+        assert_eq!(
+            parse("ffffffffffffffff: 41"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            parse("0000 41\n7fffffffffffffff"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            parse("0000 41\n*\n7fffffffffffffff"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            parse("00000000: 41\nffffffff: 41"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            parse("00000000  41  |A|\n*\n00100000"),
+            Err(FromHexError::InvalidStringLength)
+        );
+
+        // a gap as long as the input is fine
+        assert_eq!(parse("0000000c: 41").unwrap().len(), 13);
+        assert_eq!(
+            parse("0000000d: 41"),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+}
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! EIP-55 mixed-case checksum encoding of Ethereum addresses.
+//!
+//! [EIP-55](https://eips.ethereum.org/EIPS/eip-55) encodes a 20-byte address
+//! as hex, and uppercases every letter whose position has a high nibble in the
+//! Keccak-256 hash of the lowercase encoding. This way the casing works as a
+//! checksum, while the string is still valid hex.
+//!
+//! # Example
+//!
+//! ```
+//! use hex::FromHex;
+//!
+//! let address = <[u8; 20]>::from_hex("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")?;
+//!
+//! # #[cfg(feature = "alloc")]
+//! assert_eq!(hex::eip55::encode(&address), "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+//! assert!(hex::eip55::is_valid("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+//! assert!(!hex::eip55::is_valid("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+//! # Ok::<(), hex::FromHexError>(())
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use tiny_keccak::{Hasher, Keccak};
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut hash = [0; 32];
+    hasher.update(data);
+    hasher.finalize(&mut hash);
+    hash
+}
+
+// uppercases the letters of the lowercase hex digits in `digits` according to
+// their checksum.
+fn apply_checksum(digits: &mut [u8; 40]) {
+    let hash = keccak256(digits);
+    for (i, digit) in digits.iter_mut().enumerate() {
+        let nibble = hash[i / 2] >> (4 * (1 - i % 2)) & 0x0f;
+        if nibble >= 8 {
+            digit.make_ascii_uppercase();
+        }
+    }
+}
+
+/// Encodes `address` as checksummed hex string.
+///
+/// The output doesn't contain a `0x` prefix.
+///
+/// # Example
+///
+/// ```
+/// let address = [0xfb, 0x69, 0x16, 0x09, 0x5c, 0xa1, 0xdf, 0x60, 0xbb, 0x79,
+///                0xce, 0x92, 0xce, 0x3e, 0xa7, 0x4c, 0x37, 0xc5, 0xd3, 0x59];
+///
+/// assert_eq!(hex::eip55::encode(&address), "fB6916095ca1df60bB79Ce92cE3Ea74c37c5d359");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode(address: &[u8; 20]) -> String {
+    let mut digits = [0; 40];
+    encode_to_slice(address, &mut digits);
+    // the output only contains ascii characters
+    digits.iter().map(|&digit| digit as char).collect()
+}
+
+/// Encodes `address` as checksummed hex string into a mutable slice of bytes.
+///
+/// # Example
+///
+/// ```
+/// let mut digits = [0u8; 40];
+/// hex::eip55::encode_to_slice(&[0x52; 20], &mut digits);
+/// assert_eq!(&digits, b"5252525252525252525252525252525252525252");
+/// ```
+pub fn encode_to_slice(address: &[u8; 20], output: &mut [u8; 40]) {
+    crate::encode_raw(address, output, crate::HEX_CHARS_LOWER);
+    apply_checksum(output);
+}
+
+/// Checks whether `data` is a correctly checksummed address.
+///
+/// The input has to consist of exactly 40 hex digits, optionally prefixed
+/// with `0x`, and the casing of every letter has to match the checksum.
+///
+/// # Example
+///
+/// ```
+/// assert!(hex::eip55::is_valid("0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB"));
+/// assert!(hex::eip55::is_valid("dbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB"));
+/// assert!(!hex::eip55::is_valid("dbf03b407c01e7cd3cbea99509d93f8dddc8c6fb"));
+/// ```
+pub fn is_valid<T: AsRef<[u8]>>(data: T) -> bool {
+    let data = data.as_ref();
+    let data = data.strip_prefix(b"0x").unwrap_or(data);
+
+    let mut address = [0; 20];
+    if crate::decode_to_slice(data, &mut address).is_err() {
+        return false;
+    }
+
+    let mut expected = [0; 40];
+    encode_to_slice(&address, &mut expected);
+    expected == data
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // test vectors from EIP-55
+    const VECTORS: [&str; 8] = [
+        "52908400098527886E0F7030069857D2E4169EE7",
+        "8617E340B3D01FA5F11F306F4090FD50E238070D",
+        "de709f2102306220921060314715629080e2fb77",
+        "27b1fdb04752bbc536007a920d24acb045561c26",
+        "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "fB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "dbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "D1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ];
+
+    #[test]
+    fn test_vectors() {
+        for vector in VECTORS {
+            let mut address = [0; 20];
+            crate::decode_to_slice(vector, &mut address).unwrap();
+
+            let mut digits = [0; 40];
+            encode_to_slice(&address, &mut digits);
+            assert_eq!(&digits, vector.as_bytes());
+
+            assert!(is_valid(vector));
+        }
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(!is_valid("5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+        assert!(!is_valid("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAe"));
+        assert!(!is_valid("0X5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+        assert!(!is_valid(""));
+    }
+}
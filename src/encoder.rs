@@ -0,0 +1,392 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Configurable hex encoding.
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use core::fmt;
+
+use crate::{encode_to_fmt_inner, Case};
+
+/// A configurable hex encoder.
+///
+/// The plain [`encode()`](crate::encode) functions produce one contiguous run
+/// of hex digits. `Encoder` allows inserting a separator between the encoded
+/// bytes, or between groups of several bytes, which is what MAC addresses,
+/// fingerprints and debug dumps usually look like. Long output can also be
+/// wrapped into lines of a maximum width, and the whole output can be
+/// prefixed (e.g. with `0x`).
+///
+/// # Example
+///
+/// ```
+/// use hex::{Case, Encoder};
+///
+/// let encoder = Encoder::new().separator(":").case(Case::Upper);
+///
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(encoder.encode([0x00, 0x1b, 0x63, 0x84, 0x45, 0xe6]), "00:1B:63:84:45:E6");
+///
+/// let encoder = Encoder::new().separator(" ").group_size(4);
+///
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(encoder.encode(b"kiwi fruit"), "6b697769 20667275 6974");
+///
+/// let encoder = Encoder::new().wrap(8, "\n");
+///
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(encoder.encode(b"kiwi fruit"), "6b697769\n20667275\n6974");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Encoder<'a> {
+    case: Case,
+    separator: &'a str,
+    group_size: usize,
+    wrap: Option<(usize, &'a str)>,
+    prefix: &'a str,
+}
+
+impl<'a> Encoder<'a> {
+    /// Creates an encoder producing lowercase digits without any separator,
+    /// just like [`encode()`](crate::encode).
+    pub const fn new() -> Encoder<'a> {
+        Encoder {
+            case: Case::Lower,
+            separator: "",
+            group_size: 1,
+            wrap: None,
+            prefix: "",
+        }
+    }
+
+    /// Sets the letter casing of the encoded digits.
+    #[must_use]
+    pub const fn case(mut self, case: Case) -> Encoder<'a> {
+        self.case = case;
+        self
+    }
+
+    /// Sets the separator inserted between two groups of encoded bytes.
+    ///
+    /// By default every group consists of a single byte, see
+    /// [`group_size`](Self::group_size).
+    #[must_use]
+    pub const fn separator(mut self, separator: &'a str) -> Encoder<'a> {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the number of bytes encoded between two separators.
+    ///
+    /// The last group is shorter if the input length isn't a multiple of the
+    /// group size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is zero.
+    #[must_use]
+    pub const fn group_size(mut self, bytes: usize) -> Encoder<'a> {
+        assert!(bytes > 0, "group size must not be zero");
+        self.group_size = bytes;
+        self
+    }
+
+    /// Wraps the output into lines of at most `width` characters, terminated
+    /// by `line_ending`.
+    ///
+    /// Lines are only broken between two groups, in which case the line
+    /// ending replaces the separator. A group which is wider than `width`
+    /// gets a line on its own. The last line isn't terminated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero.
+    #[must_use]
+    pub const fn wrap(mut self, width: usize, line_ending: &'a str) -> Encoder<'a> {
+        assert!(width > 0, "line width must not be zero");
+        self.wrap = Some((width, line_ending));
+        self
+    }
+
+    /// Sets a prefix written once in front of the output, such as `0x`.
+    ///
+    /// The prefix isn't counted towards the line width when wrapping.
+    ///
+    /// ```
+    /// use hex::Encoder;
+    ///
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(Encoder::new().prefix("0x").encode([0x12, 0x34]), "0x1234");
+    /// ```
+    #[must_use]
+    pub const fn prefix(mut self, prefix: &'a str) -> Encoder<'a> {
+        self.prefix = prefix;
+        self
+    }
+
+    // the number of input bytes encoded on a single line.
+    fn line_size(&self) -> usize {
+        match self.wrap {
+            Some((width, _)) => {
+                let separator = self.separator.chars().count();
+                let group = self.group_size.saturating_mul(2).saturating_add(separator);
+                let groups = width.saturating_add(separator) / group;
+                groups.max(1) * self.group_size
+            }
+            None => usize::MAX,
+        }
+    }
+
+    /// Returns the exact length in bytes of the output for `len` bytes of
+    /// input.
+    ///
+    /// This takes all of the configuration into account, so it can be used
+    /// to size a buffer up front.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::Encoder;
+    ///
+    /// let encoder = Encoder::new().prefix("0x").separator(":");
+    /// assert_eq!(encoder.encoded_len(6), 19);
+    /// assert_eq!(encoder.encoded_len(0), 2);
+    /// ```
+    #[must_use]
+    pub fn encoded_len(&self, len: usize) -> usize {
+        let lines = div_ceil(len, self.line_size());
+        let groups = div_ceil(len, self.group_size);
+        let line_ending = self.wrap.map_or(0, |(_, line_ending)| line_ending.len());
+
+        self.prefix.len()
+            + len * 2
+            + (groups - lines) * self.separator.len()
+            + lines.saturating_sub(1) * line_ending
+    }
+
+    /// Encodes `data` into a newly allocated `String`.
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn encode<T: AsRef<[u8]>>(&self, data: T) -> String {
+        let data = data.as_ref();
+        let mut buf = String::with_capacity(self.encoded_len(data.len()));
+
+        // writing into a `String` never fails
+        let _ = self.encode_to_fmt(data, &mut buf);
+        buf
+    }
+
+    /// Writes the encoded `data` into a [`fmt::Write`] sink, without
+    /// allocating.
+    pub fn encode_to_fmt<T, W>(&self, data: T, w: &mut W) -> fmt::Result
+    where
+        T: AsRef<[u8]>,
+        W: fmt::Write + ?Sized,
+    {
+        let data = data.as_ref();
+        let table = self.case.table();
+
+        w.write_str(self.prefix)?;
+
+        for (i, line) in data.chunks(self.line_size()).enumerate() {
+            if let (true, Some((_, line_ending))) = (i > 0, self.wrap) {
+                w.write_str(line_ending)?;
+            }
+
+            if self.separator.is_empty() {
+                encode_to_fmt_inner(line, w, table)?;
+                continue;
+            }
+
+            for (j, group) in line.chunks(self.group_size).enumerate() {
+                if j > 0 {
+                    w.write_str(self.separator)?;
+                }
+                encode_to_fmt_inner(group, w, table)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+const fn div_ceil(lhs: usize, rhs: usize) -> usize {
+    if lhs == 0 {
+        0
+    } else {
+        (lhs - 1) / rhs + 1
+    }
+}
+
+impl Default for Encoder<'_> {
+    fn default() -> Self {
+        Encoder::new()
+    }
+}
+
+/// Encodes `data` as hex string using lowercase characters, inserting
+/// `separator` between every two bytes.
+///
+/// # Example
+///
+/// ```
+/// let mac = [0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff];
+///
+/// assert_eq!(hex::encode_with_separator(mac, ':'), "aa:bb:cc:dd:ee:ff");
+/// assert_eq!(hex::encode_with_separator([0x01], ':'), "01");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_with_separator<T: AsRef<[u8]>>(data: T, separator: char) -> String {
+    let mut buf = [0_u8; 4];
+    Encoder::new()
+        .separator(separator.encode_utf8(&mut buf))
+        .encode(data)
+}
+
+/// Encodes `data` as hex string using lowercase characters, inserting
+/// `separator` between every group of `group_bytes` bytes.
+///
+/// # Panics
+///
+/// Panics if `group_bytes` is zero.
+///
+/// # Example
+///
+/// ```
+/// let data = [0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe, 0xba, 0xbe];
+///
+/// assert_eq!(hex::encode_grouped(data, 4, ' '), "deadbeef cafebabe");
+/// assert_eq!(hex::encode_grouped(data, 2, '-'), "dead-beef-cafe-babe");
+/// assert_eq!(hex::encode_grouped(data, 3, '-'), "deadbe-efcafe-babe");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_grouped<T: AsRef<[u8]>>(data: T, group_bytes: usize, separator: char) -> String {
+    let mut buf = [0_u8; 4];
+    Encoder::new()
+        .separator(separator.encode_utf8(&mut buf))
+        .group_size(group_bytes)
+        .encode(data)
+}
+
+/// Encodes `data` as hex string using lowercase characters, wrapped into
+/// lines of at most `width` characters.
+///
+/// Lines are separated by `line_ending`, the last line isn't terminated. See
+/// [`Encoder::wrap`] for combining wrapping with separators.
+///
+/// # Panics
+///
+/// Panics if `width` is zero.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::encode_wrapped(b"Hello world!", 8, "\n"), "48656c6c\n6f20776f\n726c6421");
+/// assert_eq!(hex::encode_wrapped(b"kiwi", 7, "\r\n"), "6b6977\r\n69");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_wrapped<T: AsRef<[u8]>>(data: T, width: usize, line_ending: &str) -> String {
+    Encoder::new().wrap(width, line_ending).encode(data)
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod test {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_encode_with_separator() {
+        assert_eq!(
+            encode_with_separator([0xde, 0xad, 0xbe, 0xef], '-'),
+            "de-ad-be-ef"
+        );
+        assert_eq!(encode_with_separator([0x00, 0xff], 'é'), "00éff");
+        assert_eq!(encode_with_separator([], ':'), "");
+    }
+
+    #[test]
+    fn test_encode_grouped() {
+        assert_eq!(encode_grouped(b"kiwi", 2, ' '), "6b69 7769");
+        assert_eq!(encode_grouped(b"kiwi", 4, ' '), "6b697769");
+        assert_eq!(encode_grouped(b"kiwi", 10, ' '), "6b697769");
+        assert_eq!(encode_grouped([], 2, ' '), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "group size must not be zero")]
+    fn test_encode_grouped_zero() {
+        let _ = encode_grouped(b"kiwi", 0, ' ');
+    }
+
+    #[test]
+    fn test_encode_wrapped() {
+        let data = [0xab; 40];
+        let encoded = encode_wrapped(data, 64, "\n");
+        let lines: Vec<_> = encoded.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].len(), 64);
+        assert_eq!(lines[1].len(), 16);
+
+        assert_eq!(encode_wrapped(b"kiwi", 1, "\n"), "6b\n69\n77\n69");
+        assert_eq!(encode_wrapped(b"kiwi", 8, "\n"), "6b697769");
+        assert_eq!(encode_wrapped([], 8, "\n"), "");
+    }
+
+    #[test]
+    fn test_encoder_wrap_groups() {
+        let encoder = Encoder::new().separator(" ").group_size(2).wrap(10, "\n");
+        assert_eq!(encoder.encode(b"kiwi fruit"), "6b69 7769\n2066 7275\n6974");
+
+        // a group wider than the line
+        let encoder = Encoder::new().separator(":").group_size(4).wrap(4, "|");
+        assert_eq!(encoder.encode(b"kiwi fruit"), "6b697769|20667275|6974");
+
+        let encoder = Encoder::new()
+            .separator(" ")
+            .group_size(usize::MAX)
+            .wrap(usize::MAX, "\n");
+        assert_eq!(encoder.encode(b"kiwi"), "6b697769");
+    }
+
+    #[test]
+    fn test_encoder_encoded_len() {
+        let encoders = [
+            Encoder::new(),
+            Encoder::new().separator(", "),
+            Encoder::new().separator(" ").group_size(3),
+            Encoder::new().wrap(5, "\r\n"),
+            Encoder::new().separator("::").group_size(2).wrap(20, "\n"),
+            Encoder::new().prefix("0x").separator(" ").wrap(6, "\n"),
+        ];
+
+        for encoder in &encoders {
+            for len in 0..50 {
+                let data = vec![0x5a; len];
+                assert_eq!(encoder.encoded_len(len), encoder.encode(&data).len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_encoder_prefix() {
+        let encoder = Encoder::new().prefix("0x").separator(" ").wrap(6, "\n");
+        assert_eq!(encoder.encode(b"kiwi"), "0x6b 69\n77 69");
+        assert_eq!(encoder.encode([]), "0x");
+    }
+
+    #[test]
+    fn test_encoder() {
+        assert_eq!(Encoder::new().encode("kiwi"), "6b697769");
+        assert_eq!(
+            Encoder::new()
+                .separator(", ")
+                .case(Case::Upper)
+                .encode([0xab, 0xcd, 0xef]),
+            "AB, CD, EF"
+        );
+    }
+}
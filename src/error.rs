@@ -16,6 +16,101 @@ pub enum FromHexError {
     /// array, the hex string's length * 2 has to match the container's
     /// length.
     InvalidStringLength,
+
+    /// A digit of the wrong letter case was found, while decoding only
+    /// accepts either lowercase or uppercase digits.
+    InvalidCase { c: char, index: usize },
+
+    /// The decoded bytes aren't valid UTF-8, while decoding into a string.
+    /// `valid_up_to` is the number of decoded bytes which are valid.
+    InvalidUtf8 { valid_up_to: usize },
+
+    /// The decoded value is zero, while decoding into a non-zero integer
+    /// type.
+    Zero,
+}
+
+impl FromHexError {
+    // moves the position of an invalid character by `offset`, for errors of
+    // digits decoded from within a larger input.
+    pub(crate) const fn offset(self, offset: usize) -> FromHexError {
+        match self {
+            FromHexError::InvalidHexCharacter { c, index } => FromHexError::InvalidHexCharacter {
+                c,
+                index: index + offset,
+            },
+            FromHexError::InvalidCase { c, index } => FromHexError::InvalidCase {
+                c,
+                index: index + offset,
+            },
+            err => err,
+        }
+    }
+
+    /// Returns the line and column of an invalid character within `input`,
+    /// the string which failed to decode.
+    ///
+    /// This is useful for reporting errors in multi-line input, such as
+    /// wrapped hex or dumps decoded with a [`Decoder`](crate::Decoder).
+    /// Lines are terminated by `\n`, and columns count characters, not
+    /// bytes. Returns `None` for errors without position, or if the position
+    /// is out of range of `input`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::{Decoder, Position};
+    ///
+    /// let input = "6b69\n7769\n2066 727g";
+    /// let err = Decoder::new().ignore_whitespace(true).decode_to_slice(input, &mut [0; 8]);
+    ///
+    /// let pos = err.unwrap_err().position(input).unwrap();
+    /// assert_eq!(pos, Position { line: 3, column: 9 });
+    /// assert_eq!(pos.to_string(), "line 3, column 9");
+    /// ```
+    pub fn position<T: AsRef<[u8]>>(&self, input: T) -> Option<Position> {
+        let index = match *self {
+            FromHexError::InvalidHexCharacter { index, .. }
+            | FromHexError::InvalidCase { index, .. } => index,
+            _ => return None,
+        };
+        let input = input.as_ref();
+        if index >= input.len() {
+            return None;
+        }
+        let before = &input[..index];
+
+        let (line, start) = match before.iter().rposition(|&c| c == b'\n') {
+            Some(newline) => (before.iter().filter(|&&c| c == b'\n').count(), newline + 1),
+            None => (0, 0),
+        };
+        // count the characters, i.e. everything but UTF-8 continuation bytes
+        let column = before[start..]
+            .iter()
+            .filter(|&&c| c & 0xc0 != 0x80)
+            .count();
+
+        Some(Position {
+            line: line + 1,
+            column: column + 1,
+        })
+    }
+}
+
+/// The line and column of a character in a multi-line string, both starting
+/// at 1.
+///
+/// See [`FromHexError::position`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -29,6 +124,13 @@ impl fmt::Display for FromHexError {
             }
             FromHexError::OddLength => write!(f, "Odd number of digits"),
             FromHexError::InvalidStringLength => write!(f, "Invalid string length"),
+            FromHexError::InvalidCase { c, index } => {
+                write!(f, "Invalid letter case of {:?} at position {}", c, index)
+            }
+            FromHexError::InvalidUtf8 { valid_up_to } => {
+                write!(f, "Invalid UTF-8 after {} decoded bytes", valid_up_to)
+            }
+            FromHexError::Zero => write!(f, "Decoded value is zero"),
         }
     }
 }
@@ -56,5 +158,33 @@ mod tests {
             FromHexError::InvalidStringLength.to_string(),
             "Invalid string length"
         );
+        assert_eq!(
+            FromHexError::InvalidCase { c: 'A', index: 3 }.to_string(),
+            "Invalid letter case of 'A' at position 3"
+        );
+        assert_eq!(
+            FromHexError::InvalidUtf8 { valid_up_to: 2 }.to_string(),
+            "Invalid UTF-8 after 2 decoded bytes"
+        );
+        assert_eq!(FromHexError::Zero.to_string(), "Decoded value is zero");
+    }
+
+    #[test]
+    fn test_position() {
+        let err = FromHexError::InvalidHexCharacter { c: 'g', index: 0 };
+        assert_eq!(err.position("g"), Some(Position { line: 1, column: 1 }));
+        assert_eq!(err.position(""), None);
+
+        let input = "00\r\n\n\u{e9}\u{e9}g";
+        let err = FromHexError::InvalidHexCharacter { c: 'g', index: 9 };
+        assert_eq!(err.position(input), Some(Position { line: 3, column: 3 }));
+        let err = FromHexError::InvalidHexCharacter { c: '\n', index: 3 };
+        assert_eq!(err.position(input), Some(Position { line: 1, column: 4 }));
+
+        assert_eq!(FromHexError::OddLength.position(input), None);
+        assert_eq!(
+            Position { line: 1, column: 2 }.to_string(),
+            "line 1, column 2"
+        );
     }
 }
@@ -0,0 +1,323 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! `\x`-escaped byte strings.
+//!
+//! Many tools write binary data as a sequence of `\x` escapes, e.g.
+//! `\x48\x65\x6c\x6c\x6f`: shell one-liners (`printf`, `echo -e`), Python and C
+//! string literals, and exploit payloads.
+//!
+//! # Example
+//!
+//! ```
+//! use hex::escape::Escaper;
+//!
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! let escaper = Escaper::new().printable(true);
+//! assert_eq!(escaper.encode(b"\x7fELF"), r"\x7fELF");
+//! assert_eq!(escaper.decode(r"\x7fELF"), Ok(b"\x7fELF".to_vec()));
+//! # }
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use core::fmt;
+
+use crate::{byte2hex, val, Case, FromHexError};
+
+/// A configurable `\x` escaper.
+///
+/// By default every byte is escaped. Optionally, printable ASCII characters
+/// can be passed through unescaped, in which case the backslash itself is
+/// still escaped to keep the output unambiguous.
+///
+/// The same configuration is used for decoding: by default only `\x`
+/// escape sequences are accepted, with [`printable`](Self::printable) set,
+/// printable ASCII characters are decoded as themselves.
+///
+/// # Example
+///
+/// ```
+/// use hex::escape::Escaper;
+///
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// assert_eq!(Escaper::new().encode(b"Hi!\n"), r"\x48\x69\x21\x0a");
+/// assert_eq!(Escaper::new().printable(true).encode(b"Hi!\n"), r"Hi!\x0a");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Escaper {
+    case: Case,
+    printable: bool,
+}
+
+impl Escaper {
+    /// Creates an escaper escaping every byte using lowercase digits.
+    pub const fn new() -> Escaper {
+        Escaper {
+            case: Case::Lower,
+            printable: false,
+        }
+    }
+
+    /// Sets the letter casing of the encoded digits.
+    ///
+    /// The `\x` prefix is always lowercase.
+    #[must_use]
+    pub const fn case(mut self, case: Case) -> Escaper {
+        self.case = case;
+        self
+    }
+
+    /// Sets whether printable ASCII characters (`' '` to `'~'`) are written
+    /// as is, instead of being escaped.
+    ///
+    /// The backslash is escaped either way.
+    #[must_use]
+    pub const fn printable(mut self, printable: bool) -> Escaper {
+        self.printable = printable;
+        self
+    }
+
+    // whether `byte` is written unescaped.
+    fn passes(&self, byte: u8) -> bool {
+        self.printable && matches!(byte, b' '..=b'~') && byte != b'\\'
+    }
+
+    /// Encodes `data` into a newly allocated `String`.
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn encode<T: AsRef<[u8]>>(&self, data: T) -> String {
+        let data = data.as_ref();
+        let mut buf = String::with_capacity(data.len() * 4);
+
+        // writing into a `String` never fails
+        let _ = self.encode_to_fmt(data, &mut buf);
+        buf
+    }
+
+    /// Writes the encoded `data` into a [`fmt::Write`] sink, without
+    /// allocating.
+    pub fn encode_to_fmt<T, W>(&self, data: T, w: &mut W) -> fmt::Result
+    where
+        T: AsRef<[u8]>,
+        W: fmt::Write + ?Sized,
+    {
+        let table = self.case.table();
+
+        for &byte in data.as_ref() {
+            if self.passes(byte) {
+                w.write_char(byte as char)?;
+            } else {
+                let (high, low) = byte2hex(byte, table);
+                w.write_str("\\x")?;
+                w.write_char(high as char)?;
+                w.write_char(low as char)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decodes `\x`-escaped `data` into a newly allocated `Vec`.
+    ///
+    /// The digits of an escape sequence may be upper- or lowercase.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) for
+    /// a character which is neither part of an escape sequence nor passed
+    /// through, and [`OddLength`](FromHexError::OddLength) if the input ends
+    /// within an escape sequence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::{escape::Escaper, FromHexError};
+    ///
+    /// assert_eq!(Escaper::new().decode(r"\x48\x49"), Ok(b"HI".to_vec()));
+    /// assert_eq!(
+    ///     Escaper::new().decode(r"\x48I"),
+    ///     Err(FromHexError::InvalidHexCharacter { c: 'I', index: 4 })
+    /// );
+    /// assert_eq!(Escaper::new().printable(true).decode(r"\x48I"), Ok(b"HI".to_vec()));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn decode<T: AsRef<[u8]>>(&self, data: T) -> Result<Vec<u8>, FromHexError> {
+        let data = data.as_ref();
+        let mut out = Vec::with_capacity(data.len() / 4);
+        self.decode_with(data, |byte| {
+            out.push(byte);
+            Ok(())
+        })?;
+        Ok(out)
+    }
+
+    /// Decodes `\x`-escaped `data` into a mutable bytes slice, returning the
+    /// number of decoded bytes.
+    ///
+    /// Returns [`InvalidStringLength`](FromHexError::InvalidStringLength) if
+    /// `out` is too small to hold the decoded bytes, see
+    /// [`decode`](Self::decode) for the other errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::escape::Escaper;
+    ///
+    /// let mut bytes = [0u8; 8];
+    /// let len = Escaper::new().printable(true).decode_to_slice(r"\x00OK", &mut bytes)?;
+    /// assert_eq!(&bytes[..len], b"\0OK");
+    /// # Ok::<(), hex::FromHexError>(())
+    /// ```
+    pub fn decode_to_slice<T: AsRef<[u8]>>(
+        &self,
+        data: T,
+        out: &mut [u8],
+    ) -> Result<usize, FromHexError> {
+        let mut len = 0;
+        self.decode_with(data.as_ref(), |byte| {
+            *out.get_mut(len).ok_or(FromHexError::InvalidStringLength)? = byte;
+            len += 1;
+            Ok(())
+        })?;
+        Ok(len)
+    }
+
+    fn decode_with<F>(&self, data: &[u8], mut push: F) -> Result<(), FromHexError>
+    where
+        F: FnMut(u8) -> Result<(), FromHexError>,
+    {
+        let mut i = 0;
+        while i < data.len() {
+            let byte = data[i];
+            if self.passes(byte) {
+                push(byte)?;
+                i += 1;
+                continue;
+            }
+            if byte != b'\\' {
+                return Err(FromHexError::InvalidHexCharacter {
+                    c: byte as char,
+                    index: i,
+                });
+            }
+
+            let sequence = data.get(i..i + 4).ok_or(FromHexError::OddLength)?;
+            if sequence[1] != b'x' {
+                return Err(FromHexError::InvalidHexCharacter {
+                    c: sequence[1] as char,
+                    index: i + 1,
+                });
+            }
+            push(val(sequence[2], i + 2)? << 4 | val(sequence[3], i + 3)?)?;
+            i += 4;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Escaper {
+    fn default() -> Self {
+        Escaper::new()
+    }
+}
+
+/// Encodes every byte of `data` as `\x` escape sequence, using lowercase
+/// characters.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::escape::encode(b"kiwi"), r"\x6b\x69\x77\x69");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode<T: AsRef<[u8]>>(data: T) -> String {
+    Escaper::new().encode(data)
+}
+
+/// Decodes a string consisting of `\x` escape sequences only.
+///
+/// See [`Escaper::decode`] for details, and for accepting printable
+/// characters in between.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::escape::decode(r"\x6b\x69\x77\x69"), Ok(b"kiwi".to_vec()));
+/// assert!(hex::escape::decode(r"\x6b\x6").is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    Escaper::new().decode(data)
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode([]), "");
+        assert_eq!(encode([0x00, 0xff]), r"\x00\xff");
+        assert_eq!(
+            Escaper::new().case(Case::Upper).encode([0xab, 0x41]),
+            r"\xAB\x41"
+        );
+    }
+
+    #[test]
+    fn test_encode_printable() {
+        let escaper = Escaper::new().printable(true);
+        assert_eq!(escaper.encode(b"a b~"), "a b~");
+        assert_eq!(escaper.encode(b"\\\x7f\x1f"), r"\x5c\x7f\x1f");
+        assert_eq!(escaper.encode("é"), r"\xc3\xa9");
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode(""), Ok(vec![]));
+        assert_eq!(decode(r"\x00\xFf"), Ok(vec![0x00, 0xff]));
+        assert_eq!(decode(r"\x0"), Err(FromHexError::OddLength));
+        assert_eq!(decode(r"\"), Err(FromHexError::OddLength));
+        assert_eq!(
+            decode(r"\x0g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 })
+        );
+        assert_eq!(
+            decode(r"\y00"),
+            Err(FromHexError::InvalidHexCharacter { c: 'y', index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_decode_printable() {
+        let escaper = Escaper::new().printable(true);
+        assert_eq!(escaper.decode(r"\x48\x65llo"), Ok(b"Hello".to_vec()));
+        assert_eq!(escaper.decode(r"\x5c"), Ok(b"\\".to_vec()));
+        assert_eq!(
+            escaper.decode("a\nb"),
+            Err(FromHexError::InvalidHexCharacter { c: '\n', index: 1 })
+        );
+
+        for data in [&b"\\x\x00 ~"[..], &[0xff, 0x7f], b"printable"] {
+            assert_eq!(escaper.decode(escaper.encode(data)).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_decode_to_slice() {
+        let mut out = [0; 2];
+        assert_eq!(Escaper::new().decode_to_slice(r"\x01\x02", &mut out), Ok(2));
+        assert_eq!(out, [0x01, 0x02]);
+        assert_eq!(
+            Escaper::new().decode_to_slice(r"\x01\x02\x03", &mut out),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+}
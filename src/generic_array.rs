@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Support for `generic-array` types.
+//!
+//! [`GenericArray`] already implements [`ToHex`](crate::ToHex) through
+//! `AsRef<[u8]>`, so only decoding needs an impl of its own.
+
+// 0.14 is deprecated in favor of 1.x, but it's the version the RustCrypto
+// crates expose in their APIs
+#![allow(deprecated)]
+
+use generic_array::{ArrayLength, GenericArray};
+
+use crate::{decode_to_slice, FromHex, FromHexError};
+
+impl<N: ArrayLength<u8>> FromHex for GenericArray<u8, N> {
+    type Error = FromHexError;
+
+    /// Decodes exactly `N` bytes, like the impl for `[u8; N]`.
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        let mut out = GenericArray::default();
+        decode_to_slice(hex, &mut out)?;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use generic_array::typenum::{U0, U4};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_hex_generic_array() {
+        let bytes = GenericArray::<u8, U4>::from_hex("6b697769").unwrap();
+        assert_eq!(bytes, GenericArray::from(*b"kiwi"));
+        assert_eq!(
+            GenericArray::<u8, U0>::from_hex(""),
+            Ok(GenericArray::default())
+        );
+
+        assert_eq!(
+            GenericArray::<u8, U4>::from_hex("6b6977"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            GenericArray::<u8, U4>::from_hex("6b69776g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 7 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_to_hex_generic_array() {
+        use crate::ToHex;
+        use alloc::string::String;
+
+        let bytes = GenericArray::from(*b"kiwi");
+        assert_eq!(bytes.encode_hex::<String>(), "6b697769");
+    }
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Encoding into `heapless` strings.
+use heapless::{CapacityError, String};
+
+use crate::{encode_to_fmt_inner, HEX_CHARS_LOWER, HEX_CHARS_UPPER};
+
+/// Encodes `data` as hex string with a fixed capacity of `N` bytes, using
+/// lowercase characters.
+///
+/// Returns an error if the `data.len() * 2` digits don't fit into the string.
+///
+/// # Example
+///
+/// ```
+/// let hex: heapless::String<8> = hex::encode_heapless(b"kiwi")?;
+/// assert_eq!(hex, "6b697769");
+///
+/// assert!(hex::encode_heapless::<6, _>(b"kiwi").is_err());
+/// # Ok::<(), heapless::CapacityError>(())
+/// ```
+pub fn encode_heapless<const N: usize, T: AsRef<[u8]>>(
+    data: T,
+) -> Result<String<N>, CapacityError> {
+    encode_heapless_inner(data.as_ref(), HEX_CHARS_LOWER)
+}
+
+/// Encodes `data` as hex string with a fixed capacity of `N` bytes, using
+/// uppercase characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_heapless()`].
+///
+/// # Example
+///
+/// ```
+/// let hex: heapless::String<4> = hex::encode_upper_heapless([0xca, 0xfe])?;
+/// assert_eq!(hex, "CAFE");
+/// # Ok::<(), heapless::CapacityError>(())
+/// ```
+pub fn encode_upper_heapless<const N: usize, T: AsRef<[u8]>>(
+    data: T,
+) -> Result<String<N>, CapacityError> {
+    encode_heapless_inner(data.as_ref(), HEX_CHARS_UPPER)
+}
+
+fn encode_heapless_inner<const N: usize>(
+    data: &[u8],
+    table: &[u8; 16],
+) -> Result<String<N>, CapacityError> {
+    if data.len() * 2 > N {
+        return Err(CapacityError::default());
+    }
+
+    let mut buf = String::new();
+    encode_to_fmt_inner(data, &mut buf, table).map_err(|_| CapacityError::default())?;
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_encode_heapless() {
+        let hex: String<12> = encode_heapless("foobar").unwrap();
+        assert_eq!(hex, "666f6f626172");
+
+        let hex: String<16> = encode_upper_heapless([0xab, 0xcd]).unwrap();
+        assert_eq!(hex, "ABCD");
+
+        assert!(encode_heapless::<11, _>("foobar").is_err());
+    }
+}
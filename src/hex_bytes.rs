@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Fixed-size byte arrays formatted as hex.
+use core::{fmt, str::FromStr};
+
+use crate::{encode_to_fmt_inner, FromHex, FromHexError, HEX_CHARS_LOWER, HEX_CHARS_UPPER};
+
+/// A byte array of length `N` which is formatted and parsed as hex, such as
+/// a hash or a key.
+///
+/// The bytes are displayed as lowercase hex, and formatted as lower- or
+/// uppercase hex by `{:x}` and `{:X}`, with a `0x` prefix in the alternate
+/// form `{:#x}`. Parsing requires exactly `2 * N` digits of either case.
+///
+/// # Example
+///
+/// ```
+/// use hex::HexBytes;
+///
+/// let hash: HexBytes<4> = "6b697769".parse()?;
+/// assert_eq!(hash.0, *b"kiwi");
+///
+/// assert_eq!(hash.to_string(), "6b697769");
+/// assert_eq!(format!("{:#X}", hash), "0x6B697769");
+/// assert_eq!(format!("{:?}", hash), "HexBytes(6b697769)");
+/// # Ok::<(), hex::FromHexError>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexBytes<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> HexBytes<N> {
+    /// Compares the bytes with `other` in constant time.
+    ///
+    /// Unlike `==`, this doesn't return early at the first differing byte,
+    /// so the time taken doesn't reveal the length of the common prefix.
+    /// Use this when comparing secrets like MACs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::HexBytes;
+    ///
+    /// let mac = HexBytes([0xca, 0xfe]);
+    /// assert!(mac.ct_eq(&HexBytes([0xca, 0xfe])));
+    /// assert!(!mac.ct_eq(&HexBytes([0xca, 0xff])));
+    /// ```
+    #[must_use]
+    pub fn ct_eq(&self, other: &HexBytes<N>) -> bool {
+        let diff = self
+            .0
+            .iter()
+            .zip(&other.0)
+            .fold(0, |diff, (a, b)| diff | (a ^ b));
+        // SAFETY: `diff` is a valid, initialized local. The volatile read
+        // keeps the compiler from turning the loop into an early return.
+        unsafe { core::ptr::read_volatile(&diff) == 0 }
+    }
+}
+
+impl<const N: usize> Default for HexBytes<N> {
+    fn default() -> Self {
+        HexBytes([0; N])
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for HexBytes<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        HexBytes(bytes)
+    }
+}
+
+impl<const N: usize> From<HexBytes<N>> for [u8; N] {
+    fn from(bytes: HexBytes<N>) -> Self {
+        bytes.0
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for HexBytes<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> FromHex for HexBytes<N> {
+    type Error = FromHexError;
+
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        <[u8; N]>::from_hex(hex).map(HexBytes)
+    }
+}
+
+impl<const N: usize> FromStr for HexBytes<N> {
+    type Err = FromHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HexBytes::from_hex(s)
+    }
+}
+
+impl<const N: usize> fmt::Display for HexBytes<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        encode_to_fmt_inner(&self.0, f, HEX_CHARS_LOWER)
+    }
+}
+
+impl<const N: usize> fmt::Debug for HexBytes<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HexBytes({})", self)
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for HexBytes<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        encode_to_fmt_inner(&self.0, f, HEX_CHARS_LOWER)
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for HexBytes<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        encode_to_fmt_inner(&self.0, f, HEX_CHARS_UPPER)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for HexBytes<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(crate::Buffer::<N>::new().format(self.0))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for HexBytes<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct HexBytesVisitor<const N: usize>;
+
+        impl<'de, const N: usize> serde::de::Visitor<'de> for HexBytesVisitor<N> {
+            type Value = HexBytes<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a hex encoded string of {} bytes", N)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, data: &str) -> Result<Self::Value, E> {
+                data.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(HexBytesVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_hex_bytes_from_str() {
+        assert_eq!("6B697769".parse(), Ok(HexBytes(*b"kiwi")));
+        assert_eq!("".parse(), Ok(HexBytes::<0>([])));
+        assert_eq!(
+            "6b6977".parse::<HexBytes<4>>(),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            "6b69776g".parse::<HexBytes<4>>(),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 7 })
+        );
+        assert_eq!(HexBytes::<33>::default().0, [0; 33]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_hex_bytes_fmt() {
+        use alloc::{format, string::ToString};
+
+        let bytes = HexBytes([0x00, 0xab, 0xcd]);
+        assert_eq!(bytes.to_string(), "00abcd");
+        assert_eq!(format!("{:x}", bytes), "00abcd");
+        assert_eq!(format!("{:#x}", bytes), "0x00abcd");
+        assert_eq!(format!("{:X}", bytes), "00ABCD");
+        assert_eq!(format!("{:?}", bytes), "HexBytes(00abcd)");
+        assert_eq!(crate::encode(bytes), "00abcd");
+    }
+
+    #[test]
+    fn test_hex_bytes_ct_eq() {
+        let bytes = HexBytes(*b"kiwi");
+        assert!(bytes.ct_eq(&HexBytes(*b"kiwi")));
+        assert!(!bytes.ct_eq(&HexBytes(*b"kiwa")));
+        assert!(!bytes.ct_eq(&HexBytes(*b"Kiwi")));
+        assert!(HexBytes([]).ct_eq(&HexBytes([])));
+    }
+}
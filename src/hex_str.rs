@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Borrowed strings of valid hex.
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::{fmt, ops::Deref};
+
+#[cfg(feature = "alloc")]
+use crate::HexString;
+use crate::{decode_to_slice_unchecked, validate, FromHexError};
+
+/// A string slice which is guaranteed to contain valid hex, i.e. an even
+/// number of hex digits.
+///
+/// This is the borrowed counterpart of [`HexString`], like `str` is for
+/// `String`. The digits are validated once when the slice is created, after
+/// which they can be decoded repeatedly without checking them again.
+///
+/// # Example
+///
+/// ```
+/// use hex::HexStr;
+///
+/// let hex = HexStr::new("6b697769")?;
+///
+/// let mut out = [0; 4];
+/// hex.decode_to_slice(&mut out)?;
+/// assert_eq!(&out, b"kiwi");
+///
+/// assert!(HexStr::new("6b69776").is_err());
+/// # Ok::<(), hex::FromHexError>(())
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct HexStr {
+    digits: str,
+}
+
+impl HexStr {
+    /// Validates `digits`, borrowing them as `HexStr` if they are valid hex.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`validate()`](crate::validate).
+    pub fn new(digits: &str) -> Result<&HexStr, FromHexError> {
+        validate(digits)?;
+        Ok(HexStr::new_unchecked(digits))
+    }
+
+    // wraps digits which have already been validated.
+    pub(crate) fn new_unchecked(digits: &str) -> &HexStr {
+        // SAFETY: `HexStr` is a `repr(transparent)` wrapper around `str`.
+        unsafe { &*(digits as *const str as *const HexStr) }
+    }
+
+    /// Returns the digits as string slice.
+    pub const fn as_str(&self) -> &str {
+        &self.digits
+    }
+
+    /// Returns the number of bytes the digits decode to.
+    pub const fn decoded_len(&self) -> usize {
+        self.digits.len() / 2
+    }
+
+    /// Decodes the digits into `out`, without validating them again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidStringLength`](FromHexError::InvalidStringLength) if
+    /// the length of `out` isn't [`decoded_len`](Self::decoded_len).
+    pub fn decode_to_slice(&self, out: &mut [u8]) -> Result<(), FromHexError> {
+        decode_to_slice_unchecked(&self.digits, out)
+    }
+
+    /// Decodes the digits into a newly allocated `Vec`, without validating
+    /// them again.
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn decode(&self) -> Vec<u8> {
+        let mut out = vec![0; self.decoded_len()];
+        self.decode_to_slice(&mut out)
+            .expect("output has the decoded length");
+        out
+    }
+
+    /// Returns a copy of the digits with all letters in lowercase.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let hex = hex::HexStr::new("CAFE")?;
+    /// assert_eq!(hex.to_lowercase().as_str(), "cafe");
+    /// # Ok::<(), hex::FromHexError>(())
+    /// ```
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn to_lowercase(&self) -> HexString {
+        HexString::new_unchecked(self.digits.to_ascii_lowercase())
+    }
+
+    /// Returns a copy of the digits with all letters in uppercase.
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn to_uppercase(&self) -> HexString {
+        HexString::new_unchecked(self.digits.to_ascii_uppercase())
+    }
+}
+
+impl Deref for HexStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.digits
+    }
+}
+
+impl AsRef<str> for HexStr {
+    fn as_ref(&self) -> &str {
+        &self.digits
+    }
+}
+
+impl fmt::Display for HexStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.digits)
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a str> for &'a HexStr {
+    type Error = FromHexError;
+
+    fn try_from(digits: &'a str) -> Result<Self, Self::Error> {
+        HexStr::new(digits)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl alloc::borrow::ToOwned for HexStr {
+    type Owned = HexString;
+
+    fn to_owned(&self) -> HexString {
+        HexString::new_unchecked(self.digits.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_hex_str_new() {
+        let hex = HexStr::new("6B69").unwrap();
+        assert_eq!(hex.as_str(), "6B69");
+        assert_eq!(&**hex, "6B69");
+        assert_eq!(hex.decoded_len(), 2);
+
+        let mut out = [0; 2];
+        assert_eq!(hex.decode_to_slice(&mut out), Ok(()));
+        assert_eq!(&out, b"ki");
+        assert_eq!(
+            hex.decode_to_slice(&mut [0; 3]),
+            Err(FromHexError::InvalidStringLength)
+        );
+
+        assert_eq!(HexStr::new("6b6"), Err(FromHexError::OddLength));
+        assert_eq!(
+            HexStr::new("6g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 1 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_hex_str_owned() {
+        use alloc::{borrow::ToOwned, string::ToString};
+
+        let hex = HexStr::new("aBcD").unwrap();
+        assert_eq!(hex.decode(), [0xab, 0xcd]);
+        assert_eq!(hex.to_string(), "aBcD");
+        assert_eq!(hex.to_lowercase().as_str(), "abcd");
+        assert_eq!(hex.to_uppercase().as_str(), "ABCD");
+        assert_eq!(hex.to_owned().as_hex_str(), hex);
+    }
+}
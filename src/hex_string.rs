@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Owned strings of valid hex.
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::{borrow::Borrow, convert::TryFrom, fmt, ops::Deref, str::FromStr};
+
+use crate::{validate, FromHexError, HexStr};
+
+/// An owned string which is guaranteed to contain valid hex, i.e. an even
+/// number of hex digits.
+///
+/// As the digits are validated when the string is created, decoding it
+/// can't fail and skips the validation. Both upper- and lowercase digits are
+/// kept as they are. [`HexStr`] is its borrowed counterpart.
+///
+/// # Example
+///
+/// ```
+/// use hex::HexString;
+///
+/// let hex: HexString = "6b697769".parse()?;
+/// assert_eq!(hex.len(), 8);
+/// assert_eq!(hex.decode(), b"kiwi");
+///
+/// assert!("6b6977".parse::<HexString>().is_ok());
+/// assert!("6b697".parse::<HexString>().is_err());
+/// # Ok::<(), hex::FromHexError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexString {
+    digits: String,
+}
+
+impl HexString {
+    /// Validates `digits`, taking ownership of them if they are valid hex.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`validate()`](crate::validate).
+    pub fn new(digits: String) -> Result<HexString, FromHexError> {
+        validate(&digits)?;
+        Ok(HexString { digits })
+    }
+
+    // wraps digits which have already been validated.
+    pub(crate) fn new_unchecked(digits: String) -> HexString {
+        HexString { digits }
+    }
+
+    /// Encodes `data` using lowercase characters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(hex::HexString::encode(b"kiwi").as_str(), "6b697769");
+    /// ```
+    #[must_use]
+    pub fn encode<T: AsRef<[u8]>>(data: T) -> HexString {
+        HexString {
+            digits: crate::encode(data),
+        }
+    }
+
+    /// Encodes `data` using uppercase characters.
+    #[must_use]
+    pub fn encode_upper<T: AsRef<[u8]>>(data: T) -> HexString {
+        HexString {
+            digits: crate::encode_upper(data),
+        }
+    }
+
+    /// Returns the digits as string slice.
+    pub fn as_str(&self) -> &str {
+        &self.digits
+    }
+
+    /// Returns the digits as [`HexStr`].
+    pub fn as_hex_str(&self) -> &HexStr {
+        HexStr::new_unchecked(&self.digits)
+    }
+
+    /// Returns the digits as `String`, consuming `self`.
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.digits
+    }
+
+    /// Returns the number of bytes the digits decode to.
+    pub fn decoded_len(&self) -> usize {
+        self.digits.len() / 2
+    }
+
+    /// Decodes the digits into a newly allocated `Vec`.
+    ///
+    /// The digits aren't validated again, so this is cheaper than
+    /// [`decode()`](crate::decode) and can't fail.
+    #[must_use]
+    pub fn decode(&self) -> Vec<u8> {
+        self.as_hex_str().decode()
+    }
+}
+
+impl Borrow<HexStr> for HexString {
+    fn borrow(&self) -> &HexStr {
+        self.as_hex_str()
+    }
+}
+
+impl Deref for HexString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.digits
+    }
+}
+
+impl AsRef<str> for HexString {
+    fn as_ref(&self) -> &str {
+        &self.digits
+    }
+}
+
+impl fmt::Display for HexString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.digits)
+    }
+}
+
+impl FromStr for HexString {
+    type Err = FromHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate(s)?;
+        Ok(HexString {
+            digits: s.to_owned(),
+        })
+    }
+}
+
+impl TryFrom<String> for HexString {
+    type Error = FromHexError;
+
+    fn try_from(digits: String) -> Result<Self, Self::Error> {
+        HexString::new(digits)
+    }
+}
+
+impl From<HexString> for String {
+    fn from(hex: HexString) -> String {
+        hex.digits
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HexString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.digits)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HexString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct HexStringVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for HexStringVisitor {
+            type Value = HexString;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a hex encoded string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, data: &str) -> Result<Self::Value, E> {
+                data.parse().map_err(E::custom)
+            }
+
+            fn visit_string<E: serde::de::Error>(self, data: String) -> Result<Self::Value, E> {
+                HexString::new(data).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_string(HexStringVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_hex_string_new() {
+        let hex = HexString::new("6B69".to_string()).unwrap();
+        assert_eq!(hex.as_str(), "6B69");
+        assert_eq!(hex.decoded_len(), 2);
+        assert_eq!(hex.decode(), b"ki");
+        assert_eq!(HexString::default().decode(), b"");
+
+        assert_eq!(
+            HexString::new("6b6".to_string()),
+            Err(FromHexError::OddLength)
+        );
+        assert_eq!(
+            HexString::try_from("6g".to_string()),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_hex_string_str() {
+        let hex: HexString = "00ff".parse().unwrap();
+        assert_eq!(hex.to_string(), "00ff");
+        assert_eq!(&*hex, "00ff");
+        assert!(hex.starts_with("00"));
+        assert_eq!(String::from(hex), "00ff");
+
+        assert_eq!(HexString::encode([0xab, 0xcd]).as_str(), "abcd");
+        assert_eq!(HexString::encode_upper([0xab, 0xcd]).into_string(), "ABCD");
+    }
+}
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Identifiers written in hex.
+//!
+//! Some identifiers have a well-known textual form consisting of groups of
+//! hex digits. The functions in this module decode them into plain byte
+//! arrays, validating the layout of the groups on the way.
+use crate::{decode_to_slice, FromHexError};
+
+/// Decodes a hyphenated UUID such as `550e8400-e29b-41d4-a716-446655440000`
+/// into its 16 bytes.
+///
+/// The digits may be upper- or lowercase, but the hyphens have to be in the
+/// usual 8-4-4-4-12 positions. The version and variant of the UUID aren't
+/// checked.
+///
+/// # Errors
+///
+/// Returns [`InvalidStringLength`](FromHexError::InvalidStringLength) if the
+/// input isn't 36 characters long, and
+/// [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) for an invalid
+/// digit or a misplaced hyphen.
+///
+/// # Example
+///
+/// ```
+/// use hex::{ident::decode_uuid, FromHexError};
+///
+/// assert_eq!(
+///     decode_uuid("550e8400-e29b-41d4-a716-446655440000"),
+///     Ok([
+///         0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4,
+///         0xa7, 0x16, 0x44, 0x66, 0x55, 0x44, 0x00, 0x00,
+///     ])
+/// );
+/// assert_eq!(
+///     decode_uuid("550e8400e-29b-41d4-a716-446655440000"),
+///     Err(FromHexError::InvalidHexCharacter { c: 'e', index: 8 })
+/// );
+/// ```
+pub fn decode_uuid<T: AsRef<[u8]>>(data: T) -> Result<[u8; 16], FromHexError> {
+    let mut out = [0; 16];
+    decode_groups(data.as_ref(), &[8, 4, 4, 4, 12], b'-', &mut out)?;
+    Ok(out)
+}
+
+/// Decodes a MAC address (EUI-48) into its 6 bytes.
+///
+/// The following notations are accepted, with upper- or lowercase digits:
+///
+/// - colon-separated bytes, e.g. `00:1b:63:84:45:e6`,
+/// - hyphen-separated bytes, e.g. `00-1B-63-84-45-E6`,
+/// - dot-separated groups of two bytes as used by Cisco, e.g.
+///   `001b.6384.45e6`.
+///
+/// The separators have to be used consistently.
+///
+/// # Errors
+///
+/// Returns [`InvalidStringLength`](FromHexError::InvalidStringLength) if the
+/// input doesn't have the length of any of the notations, and
+/// [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) for an invalid
+/// digit or separator.
+///
+/// # Example
+///
+/// ```
+/// use hex::{ident::decode_mac, FromHexError};
+///
+/// let mac = [0x00, 0x1b, 0x63, 0x84, 0x45, 0xe6];
+///
+/// assert_eq!(decode_mac("00:1b:63:84:45:e6"), Ok(mac));
+/// assert_eq!(decode_mac("00-1B-63-84-45-E6"), Ok(mac));
+/// assert_eq!(decode_mac("001b.6384.45e6"), Ok(mac));
+/// assert_eq!(
+///     decode_mac("00:1b:63-84-45-e6"),
+///     Err(FromHexError::InvalidHexCharacter { c: '-', index: 8 })
+/// );
+/// ```
+pub fn decode_mac<T: AsRef<[u8]>>(data: T) -> Result<[u8; 6], FromHexError> {
+    let mut out = [0; 6];
+    decode_address(data.as_ref(), &mut out)?;
+    Ok(out)
+}
+
+/// Decodes an EUI-64 hardware address into its 8 bytes.
+///
+/// The same notations as for [`decode_mac`] are accepted, e.g.
+/// `02:1b:63:ff:fe:84:45:e6` or `021b.63ff.fe84.45e6`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode_mac`].
+///
+/// # Example
+///
+/// ```
+/// use hex::ident::decode_eui64;
+///
+/// let eui = [0x02, 0x1b, 0x63, 0xff, 0xfe, 0x84, 0x45, 0xe6];
+///
+/// assert_eq!(decode_eui64("02-1b-63-ff-fe-84-45-e6"), Ok(eui));
+/// assert_eq!(decode_eui64("021b.63ff.fe84.45e6"), Ok(eui));
+/// ```
+pub fn decode_eui64<T: AsRef<[u8]>>(data: T) -> Result<[u8; 8], FromHexError> {
+    let mut out = [0; 8];
+    decode_address(data.as_ref(), &mut out)?;
+    Ok(out)
+}
+
+// decodes a hardware address of `out.len()` bytes in any of the notations
+// accepted by `decode_mac`.
+fn decode_address(data: &[u8], out: &mut [u8]) -> Result<(), FromHexError> {
+    let len = out.len();
+
+    if data.len() == len * 3 - 1 {
+        let separator = match data[2] {
+            c @ (b':' | b'-') => c,
+            c => {
+                return Err(FromHexError::InvalidHexCharacter {
+                    c: c as char,
+                    index: 2,
+                })
+            }
+        };
+        decode_groups(data, &[2; 8][..len], separator, out)
+    } else if data.len() == len * 5 / 2 - 1 {
+        decode_groups(data, &[4; 4][..len / 2], b'.', out)
+    } else {
+        Err(FromHexError::InvalidStringLength)
+    }
+}
+
+// decodes `data` consisting of groups of the given numbers of digits, which
+// are separated by `separator`.
+fn decode_groups(
+    data: &[u8],
+    groups: &[usize],
+    separator: u8,
+    out: &mut [u8],
+) -> Result<(), FromHexError> {
+    let digits: usize = groups.iter().sum();
+    if data.len() != digits + groups.len() - 1 {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    let mut start = 0;
+    let mut written = 0;
+    for (i, &len) in groups.iter().enumerate() {
+        if i > 0 {
+            if data[start] != separator {
+                return Err(FromHexError::InvalidHexCharacter {
+                    c: data[start] as char,
+                    index: start,
+                });
+            }
+            start += 1;
+        }
+
+        decode_to_slice(
+            &data[start..start + len],
+            &mut out[written..written + len / 2],
+        )
+        .map_err(|err| err.offset(start))?;
+        start += len;
+        written += len / 2;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_decode_uuid() {
+        assert_eq!(
+            decode_uuid("00000000-0000-0000-0000-000000000000"),
+            Ok([0; 16])
+        );
+        assert_eq!(
+            decode_uuid("FFFFFFFF-FFFF-ffff-FFFF-FFFFFFFFFFFF"),
+            Ok([0xff; 16])
+        );
+        assert_eq!(
+            decode_uuid("123e4567-e89b-12d3-a456-42661417400"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_uuid("123e4567e89b12d3a456426614174000"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_uuid("123e4567-e89b-12d3-a456:426614174000"),
+            Err(FromHexError::InvalidHexCharacter { c: ':', index: 23 })
+        );
+        assert_eq!(
+            decode_uuid("123e4567-e89b-12d3-a456-42661417400g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 35 })
+        );
+        assert_eq!(
+            decode_uuid("{123e4567-e89b-12d3-a456-4266141740}"),
+            Err(FromHexError::InvalidHexCharacter { c: '{', index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_decode_mac() {
+        let mac = [0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff];
+        assert_eq!(decode_mac("aa:bb:cc:dd:ee:ff"), Ok(mac));
+        assert_eq!(decode_mac("AA-BB-CC-DD-EE-FF"), Ok(mac));
+        assert_eq!(decode_mac("aabb.ccdd.EEFF"), Ok(mac));
+
+        assert_eq!(decode_mac(""), Err(FromHexError::InvalidStringLength));
+        assert_eq!(
+            decode_mac("aabbccddeeff"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_mac("aa:bb:cc:dd:ee:ff:00:11"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_mac("aa.bb.cc.dd.ee.ff"),
+            Err(FromHexError::InvalidHexCharacter { c: '.', index: 2 })
+        );
+        assert_eq!(
+            decode_mac("aabb:ccdd:eeff"),
+            Err(FromHexError::InvalidHexCharacter { c: ':', index: 4 })
+        );
+        assert_eq!(
+            decode_mac("aa:bb:cc:dd:ee:fg"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 16 })
+        );
+    }
+
+    #[test]
+    fn test_decode_eui64() {
+        let eui = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        assert_eq!(decode_eui64("00:11:22:33:44:55:66:77"), Ok(eui));
+        assert_eq!(decode_eui64("0011.2233.4455.6677"), Ok(eui));
+        assert_eq!(
+            decode_eui64("00:11:22:33:44:55"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_eui64("0011.2233.4455-6677"),
+            Err(FromHexError::InvalidHexCharacter { c: '-', index: 14 })
+        );
+    }
+}
@@ -0,0 +1,437 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Fixed-width integers.
+//!
+//! Binary protocols and register dumps contain integers as a fixed number of
+//! bytes in a given byte order. The [`Integer`] trait transcodes them
+//! directly, without decoding into an array and calling `from_be_bytes`
+//! manually. Floating point numbers are transcoded by their bit pattern, see
+//! [`encode_f32_bits()`] and friends.
+//!
+//! # Example
+//!
+//! ```
+//! use hex::int::Integer;
+//!
+//! assert_eq!(u32::from_hex_be("deadbeef"), Ok(0xdeadbeef));
+//! assert_eq!(u16::from_hex_le("3412"), Ok(0x1234));
+//! assert!(u32::from_hex_be("beef").is_err());
+//! ```
+//!
+//! The non-zero integer types implement [`FromHex`], decoding a big-endian
+//! value which mustn't be zero:
+//!
+//! ```
+//! use core::num::NonZeroU16;
+//! use hex::{FromHex, FromHexError};
+//!
+//! assert_eq!(NonZeroU16::from_hex("002a"), Ok(NonZeroU16::new(42).unwrap()));
+//! assert_eq!(NonZeroU16::from_hex("0000"), Err(FromHexError::Zero));
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+#[cfg(feature = "alloc")]
+use crate::HEX_CHARS_LOWER;
+use crate::{decode_to_slice, val, Endian, FromHex, FromHexError};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Integer types which can be transcoded as a fixed number of bytes.
+///
+/// Every value is represented by exactly `2 * size_of::<Self>()` digits,
+/// including leading zeros. Signed integers are represented by their two's
+/// complement bit pattern, so `ff` decodes to `-1_i8`.
+///
+/// This trait is sealed and implemented for all primitive integer types.
+pub trait Integer: private::Sealed + Sized {
+    /// Decodes an integer from its bytes in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidStringLength`](FromHexError::InvalidStringLength)
+    /// if `hex` doesn't have exactly `2 * size_of::<Self>()` digits, and
+    /// [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) for an
+    /// invalid digit.
+    fn from_hex_endian<T: AsRef<[u8]>>(hex: T, endian: Endian) -> Result<Self, FromHexError>;
+
+    /// Decodes an integer from its big-endian bytes.
+    ///
+    /// See [`from_hex_endian`](Self::from_hex_endian) for the errors.
+    fn from_hex_be<T: AsRef<[u8]>>(hex: T) -> Result<Self, FromHexError> {
+        Self::from_hex_endian(hex, Endian::Big)
+    }
+
+    /// Decodes an integer from its little-endian bytes.
+    ///
+    /// See [`from_hex_endian`](Self::from_hex_endian) for the errors.
+    fn from_hex_le<T: AsRef<[u8]>>(hex: T) -> Result<Self, FromHexError> {
+        Self::from_hex_endian(hex, Endian::Little)
+    }
+
+    /// Encodes the integer's bytes in the given byte order, using lowercase
+    /// characters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::{int::Integer, Endian};
+    ///
+    /// assert_eq!(0x1234_u16.encode_hex_endian(Endian::Little), "3412");
+    /// assert_eq!(1_u32.encode_hex_be(), "00000001");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn encode_hex_endian(self, endian: Endian) -> String;
+
+    /// Encodes the integer's big-endian bytes, using lowercase characters.
+    #[cfg(feature = "alloc")]
+    fn encode_hex_be(self) -> String {
+        self.encode_hex_endian(Endian::Big)
+    }
+
+    /// Encodes the integer's little-endian bytes, using lowercase
+    /// characters.
+    #[cfg(feature = "alloc")]
+    fn encode_hex_le(self) -> String {
+        self.encode_hex_endian(Endian::Little)
+    }
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty)*) => {$(
+        impl private::Sealed for $ty {}
+
+        impl Integer for $ty {
+            fn from_hex_endian<T: AsRef<[u8]>>(
+                hex: T,
+                endian: Endian,
+            ) -> Result<Self, FromHexError> {
+                let mut buf = [0; core::mem::size_of::<$ty>()];
+                decode_to_slice(hex, &mut buf)?;
+                Ok(match endian {
+                    Endian::Big => <$ty>::from_be_bytes(buf),
+                    Endian::Little => <$ty>::from_le_bytes(buf),
+                })
+            }
+
+            #[cfg(feature = "alloc")]
+            fn encode_hex_endian(self, endian: Endian) -> String {
+                match endian {
+                    Endian::Big => crate::encode(self.to_be_bytes()),
+                    Endian::Little => crate::encode(self.to_le_bytes()),
+                }
+            }
+        }
+    )*};
+}
+
+impl_integer!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! impl_from_hex_non_zero {
+    ($($ty:ty => $int:ty)*) => {$(
+        impl FromHex for $ty {
+            type Error = FromHexError;
+
+            /// Decodes a big-endian value, returning
+            /// [`Zero`](FromHexError::Zero) if it's zero.
+            fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+                <$ty>::new(<$int>::from_hex_be(hex)?).ok_or(FromHexError::Zero)
+            }
+        }
+    )*};
+}
+
+impl_from_hex_non_zero! {
+    NonZeroU8 => u8
+    NonZeroU16 => u16
+    NonZeroU32 => u32
+    NonZeroU64 => u64
+    NonZeroU128 => u128
+    NonZeroUsize => usize
+}
+
+/// Encodes the IEEE 754 bit pattern of an `f32` as 8 digits, most
+/// significant first, using lowercase characters.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::int::encode_f32_bits(1.0), "3f800000");
+/// assert_eq!(hex::int::encode_f32_bits(-0.0), "80000000");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_f32_bits(value: f32) -> String {
+    value.to_bits().encode_hex_be()
+}
+
+/// Decodes an `f32` from the 8 digits of its IEEE 754 bit pattern, most
+/// significant first.
+///
+/// This is the inverse of [`encode_f32_bits()`], and works for any bit
+/// pattern, including NaN payloads.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::int::decode_f32_bits("40490fdb"), Ok(core::f32::consts::PI));
+/// assert!(hex::int::decode_f32_bits("7fc00000").unwrap().is_nan());
+/// ```
+pub fn decode_f32_bits<T: AsRef<[u8]>>(hex: T) -> Result<f32, FromHexError> {
+    u32::from_hex_be(hex).map(f32::from_bits)
+}
+
+/// Encodes the IEEE 754 bit pattern of an `f64` as 16 digits, most
+/// significant first, using lowercase characters.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::int::encode_f64_bits(1.0), "3ff0000000000000");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_f64_bits(value: f64) -> String {
+    value.to_bits().encode_hex_be()
+}
+
+/// Decodes an `f64` from the 16 digits of its IEEE 754 bit pattern, most
+/// significant first.
+///
+/// This is the inverse of [`encode_f64_bits()`].
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::int::decode_f64_bits("c000000000000000"), Ok(-2.0));
+/// ```
+pub fn decode_f64_bits<T: AsRef<[u8]>>(hex: T) -> Result<f64, FromHexError> {
+    u64::from_hex_be(hex).map(f64::from_bits)
+}
+
+/// Encodes an unsigned integer with as few digits as possible, using
+/// lowercase characters.
+///
+/// Unlike [`Integer::encode_hex_be`], leading zeros are left out, but at
+/// least one digit is always written. This is how numbers are usually
+/// written by humans, and e.g. in JSON-RPC after the `0x` prefix.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::int::encode_uint_minimal(0x2a), "2a");
+/// assert_eq!(hex::int::encode_uint_minimal(0x100), "100");
+/// assert_eq!(hex::int::encode_uint_minimal(0), "0");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_uint_minimal(value: u128) -> String {
+    let bits = 128 - value.leading_zeros() as usize;
+    let digits = core::cmp::max((bits + 3) / 4, 1);
+
+    (0..digits)
+        .rev()
+        .map(|i| HEX_CHARS_LOWER[(value >> (4 * i)) as usize & 0x0f] as char)
+        .collect()
+}
+
+/// Decodes an unsigned integer written with any number of digits from 1 to
+/// 32, most significant first.
+///
+/// This is the inverse of [`encode_uint_minimal()`]. Leading zeros are
+/// accepted, and the digits may be upper- or lowercase.
+///
+/// # Errors
+///
+/// Returns [`InvalidStringLength`](FromHexError::InvalidStringLength) if
+/// `hex` is empty or has more than 32 digits, i.e. if the value could
+/// overflow a `u128`, and
+/// [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) for an invalid
+/// digit.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::int::decode_uint_minimal("2a"), Ok(0x2a));
+/// assert_eq!(hex::int::decode_uint_minimal("0FFF"), Ok(0xfff));
+/// assert_eq!(hex::int::decode_uint_minimal(""), Err(FromHexError::InvalidStringLength));
+/// ```
+pub fn decode_uint_minimal<T: AsRef<[u8]>>(hex: T) -> Result<u128, FromHexError> {
+    let hex = hex.as_ref();
+    if hex.is_empty() || hex.len() > 32 {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    hex.iter()
+        .enumerate()
+        .try_fold(0, |value, (i, &c)| Ok(value << 4 | u128::from(val(c, i)?)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_hex_endian() {
+        assert_eq!(u8::from_hex_be("ff"), Ok(0xff));
+        assert_eq!(u16::from_hex_be("0102"), Ok(0x0102));
+        assert_eq!(u16::from_hex_le("0102"), Ok(0x0201));
+        assert_eq!(u64::from_hex_be("0000000000000001"), Ok(1));
+        assert_eq!(
+            u128::from_hex_le("0f0e0d0c0b0a09080706050403020100"),
+            Ok(0x000102030405060708090a0b0c0d0e0f)
+        );
+        assert_eq!(
+            usize::from_hex_be([b'0'; 2 * core::mem::size_of::<usize>()]),
+            Ok(0)
+        );
+
+        assert_eq!(
+            u32::from_hex_be("beef"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            u32::from_hex_be("deadbeef00"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            u32::from_hex_le("deadbeeg"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 7 })
+        );
+    }
+
+    #[test]
+    fn test_from_hex_signed() {
+        assert_eq!(i8::from_hex_be("ff"), Ok(-1));
+        assert_eq!(i8::from_hex_be("80"), Ok(i8::MIN));
+        assert_eq!(i16::from_hex_be("7fff"), Ok(i16::MAX));
+        assert_eq!(i16::from_hex_le("feff"), Ok(-2));
+        assert_eq!(i32::from_hex_be("fffffff6"), Ok(-10));
+        assert_eq!(i64::from_hex_le("0000000000000080"), Ok(i64::MIN));
+        assert_eq!(i128::from_hex_be([b'f'; 32]), Ok(-1));
+        assert_eq!(
+            isize::from_hex_be([b'f'; 2 * core::mem::size_of::<isize>()]),
+            Ok(-1)
+        );
+        assert_eq!(
+            i16::from_hex_be("ff"),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn test_from_hex_non_zero() {
+        assert_eq!(NonZeroU8::from_hex("01"), Ok(NonZeroU8::new(1).unwrap()));
+        assert_eq!(
+            NonZeroU32::from_hex("deadbeef"),
+            Ok(NonZeroU32::new(0xdeadbeef).unwrap())
+        );
+        assert_eq!(
+            NonZeroU128::from_hex([b'f'; 32]),
+            Ok(NonZeroU128::new(u128::MAX).unwrap())
+        );
+
+        assert_eq!(NonZeroU8::from_hex("00"), Err(FromHexError::Zero));
+        assert_eq!(
+            NonZeroU64::from_hex("0000000000000000"),
+            Err(FromHexError::Zero)
+        );
+        assert_eq!(
+            NonZeroUsize::from_hex("01"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            NonZeroU16::from_hex("0g00"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_decode_float_bits() {
+        assert_eq!(decode_f32_bits("00000000"), Ok(0.0));
+        assert_eq!(decode_f32_bits("7f800000"), Ok(f32::INFINITY));
+        assert_eq!(
+            decode_f32_bits("7fc00001").map(f32::to_bits),
+            Ok(0x7fc00001)
+        );
+        assert_eq!(decode_f64_bits("3FF8000000000000"), Ok(1.5));
+        assert_eq!(decode_f64_bits("fff0000000000000"), Ok(f64::NEG_INFINITY));
+
+        assert_eq!(
+            decode_f32_bits("3ff0000000000000"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_f64_bits("3f800000"),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_float_bits() {
+        assert_eq!(encode_f32_bits(0.0), "00000000");
+        assert_eq!(encode_f32_bits(f32::NEG_INFINITY), "ff800000");
+        assert_eq!(encode_f64_bits(0.1), "3fb999999999999a");
+        assert_eq!(decode_f64_bits(encode_f64_bits(-1e300)), Ok(-1e300));
+    }
+
+    #[test]
+    fn test_decode_uint_minimal() {
+        assert_eq!(decode_uint_minimal("0"), Ok(0));
+        assert_eq!(decode_uint_minimal("00000"), Ok(0));
+        assert_eq!(decode_uint_minimal("f"), Ok(15));
+        assert_eq!(decode_uint_minimal("DeadBeef"), Ok(0xdeadbeef));
+        assert_eq!(decode_uint_minimal([b'f'; 32]), Ok(u128::MAX));
+
+        assert_eq!(
+            decode_uint_minimal([b'0'; 33]),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_uint_minimal("0x2a"),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 1 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_uint_minimal() {
+        assert_eq!(encode_uint_minimal(0), "0");
+        assert_eq!(encode_uint_minimal(1), "1");
+        assert_eq!(encode_uint_minimal(0xf), "f");
+        assert_eq!(encode_uint_minimal(0x10), "10");
+        assert_eq!(encode_uint_minimal(0xabcdef), "abcdef");
+        assert_eq!(encode_uint_minimal(u128::MAX), "f".repeat(32));
+
+        for value in [0, 1, 0x1234, u64::MAX.into(), u128::MAX] {
+            assert_eq!(decode_uint_minimal(encode_uint_minimal(value)), Ok(value));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_hex_endian() {
+        assert_eq!(0xab_u8.encode_hex_be(), "ab");
+        assert_eq!(0xab_u8.encode_hex_le(), "ab");
+        assert_eq!(1_u32.encode_hex_be(), "00000001");
+        assert_eq!(1_u32.encode_hex_le(), "01000000");
+        assert_eq!(
+            0xdeadbeef_u64.encode_hex_endian(Endian::Big),
+            "00000000deadbeef"
+        );
+
+        let value = 0x0123456789abcdef_fedcba9876543210_u128;
+        assert_eq!(u128::from_hex_le(value.encode_hex_le()), Ok(value));
+
+        assert_eq!((-1_i8).encode_hex_be(), "ff");
+        assert_eq!((-2_i16).encode_hex_le(), "feff");
+        assert_eq!(i32::MIN.encode_hex_be(), "80000000");
+        assert_eq!(i64::from_hex_be((-42_i64).encode_hex_be()), Ok(-42));
+    }
+}
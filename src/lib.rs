@@ -35,12 +35,109 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 #[cfg(feature = "alloc")]
-use alloc::{string::String, vec::Vec};
+use alloc::{borrow::Cow, boxed::Box, collections::VecDeque, string::String, vec::Vec};
 
-use core::iter;
+use core::{fmt, iter, mem::MaybeUninit, ops::Deref};
 
+mod alphabet;
+pub mod base16;
+pub mod bcd;
+mod buffer;
+mod decoder;
+mod display;
+pub mod dump;
+#[cfg(feature = "eip55")]
+#[cfg_attr(docsrs, doc(cfg(feature = "eip55")))]
+pub mod eip55;
+mod encoder;
 mod error;
-pub use crate::error::FromHexError;
+pub mod escape;
+mod hex_bytes;
+mod hex_str;
+#[cfg(feature = "alloc")]
+mod hex_string;
+pub mod ident;
+pub mod int;
+pub mod literal;
+pub mod scan;
+pub mod sql;
+mod words;
+pub use crate::alphabet::{decode_to_slice_with_alphabet, encode_to_slice_with_alphabet, Alphabet};
+#[cfg(feature = "alloc")]
+pub use crate::alphabet::{decode_with_alphabet, encode_with_alphabet};
+pub use crate::buffer::Buffer;
+#[cfg(feature = "alloc")]
+pub use crate::decoder::{
+    decode_left_padded, decode_lenient, decode_lossy, decode_lower_strict, decode_prefixed,
+    decode_upper_strict, decode_with_separators, parse_any,
+};
+#[cfg(feature = "alloc")]
+pub use crate::decoder::{decode_many, DecodeMany};
+pub use crate::decoder::{Decoder, Lossy, Prefix};
+pub use crate::display::{abbrev, display, Abbrev, HexDisplay, HexList};
+pub use crate::encoder::Encoder;
+#[cfg(feature = "alloc")]
+pub use crate::encoder::{encode_grouped, encode_with_separator, encode_wrapped};
+pub use crate::error::{FromHexError, Position};
+pub use crate::hex_bytes::HexBytes;
+pub use crate::hex_str::HexStr;
+#[cfg(feature = "alloc")]
+pub use crate::hex_string::HexString;
+#[cfg(feature = "alloc")]
+pub use crate::words::{decode_words, encode_upper_words, encode_words};
+pub use crate::words::{Endian, Word};
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+pub use crate::arrayvec::{encode_arrayvec, encode_upper_arrayvec};
+
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+pub use crate::heapless::{encode_heapless, encode_upper_heapless};
+
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "smallvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+pub use crate::smallvec::{encode_smallvec, encode_upper_smallvec};
+
+#[cfg(feature = "tinyvec")]
+mod tinyvec;
+
+#[cfg(feature = "generic-array")]
+mod generic_array;
+
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "num-bigint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-bigint")))]
+pub use crate::num_bigint::{encode_bigint, encode_biguint};
+
+#[cfg(feature = "uuid")]
+mod uuid;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+pub use crate::bytemuck::decode_pod;
+#[cfg(all(feature = "bytemuck", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+pub use crate::bytemuck::encode_pod;
+
+#[cfg(feature = "bytes")]
+mod bytes;
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+pub use crate::bytes::{encode_to_bytes, encode_upper_to_bytes};
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use hex_derive::{FromHex, ToHex};
 
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
@@ -73,38 +170,263 @@ pub trait ToHex {
     /// Encode the hex strict representing `self` into the result. Upper case
     /// letters are used (e.g. `F9B4CA`)
     fn encode_hex_upper<T: iter::FromIterator<char>>(&self) -> T;
+
+    /// Encode the hex strict representing `self` into the result, using the
+    /// letter casing selected at runtime by `case`.
+    ///
+    /// ```
+    /// use hex::{Case, ToHex};
+    ///
+    /// assert_eq!("kiwi".encode_hex_case::<String>(Case::Lower), "6b697769");
+    /// assert_eq!([0xca, 0xfe].encode_hex_case::<String>(Case::Upper), "CAFE");
+    /// ```
+    fn encode_hex_case<T: iter::FromIterator<char>>(&self, case: Case) -> T {
+        match case {
+            Case::Lower => self.encode_hex(),
+            Case::Upper => self.encode_hex_upper(),
+        }
+    }
+
+    /// Write the hex string representing `self` into the given
+    /// [`fmt::Write`] sink. Lower case letters are used (e.g. `f9b4ca`)
+    ///
+    /// Implementations for types which implement `AsRef<[u8]>` write the
+    /// output without allocating (see [`encode_to_fmt()`]). The default
+    /// implementation goes through [`encode_hex`](Self::encode_hex) into a
+    /// `String`. Without the `alloc` feature, it has nowhere to collect the
+    /// output and always fails, so `no_std` implementors should override it.
+    ///
+    /// ```
+    /// use hex::ToHex;
+    ///
+    /// let mut s = String::from("0x");
+    /// [0xde, 0xad].encode_hex_to(&mut s).unwrap();
+    /// assert_eq!(s, "0xdead");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn encode_hex_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(&self.encode_hex::<String>())
+    }
+
+    /// Write the hex string representing `self` into the given
+    /// [`fmt::Write`] sink. Lower case letters are used (e.g. `f9b4ca`)
+    ///
+    /// Implementations for types which implement `AsRef<[u8]>` write the
+    /// output without allocating (see [`encode_to_fmt()`]). Without the
+    /// `alloc` feature, the default implementation has nowhere to collect the
+    /// output of [`encode_hex`](Self::encode_hex) and always fails with
+    /// [`fmt::Error`], so `no_std` implementors should override it.
+    #[cfg(not(feature = "alloc"))]
+    fn encode_hex_to<W: fmt::Write + ?Sized>(&self, _w: &mut W) -> fmt::Result {
+        Err(fmt::Error)
+    }
+
+    /// Write the hex string representing `self` into the given
+    /// [`fmt::Write`] sink. Upper case letters are used (e.g. `F9B4CA`)
+    ///
+    /// Apart from the characters' casing, this works exactly like
+    /// [`encode_hex_to`](Self::encode_hex_to).
+    #[cfg(feature = "alloc")]
+    fn encode_hex_upper_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(&self.encode_hex_upper::<String>())
+    }
+
+    /// Write the hex string representing `self` into the given
+    /// [`fmt::Write`] sink. Upper case letters are used (e.g. `F9B4CA`)
+    ///
+    /// Apart from the characters' casing, this works exactly like
+    /// [`encode_hex_to`](Self::encode_hex_to).
+    #[cfg(not(feature = "alloc"))]
+    fn encode_hex_upper_to<W: fmt::Write + ?Sized>(&self, _w: &mut W) -> fmt::Result {
+        Err(fmt::Error)
+    }
+}
+
+/// The letter casing used for the hex digits `a` to `f`.
+///
+/// This allows choosing the output casing at runtime, e.g. from a
+/// configuration option, instead of calling different functions.
+///
+/// # Example
+///
+/// ```
+/// use hex::Case;
+///
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(hex::encode_with_case([0xab, 0xcd], Case::Upper), "ABCD");
+/// assert_eq!(Case::default(), Case::Lower);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// Lowercase letters are used (e.g. `f9b4ca`).
+    Lower,
+    /// Uppercase letters are used (e.g. `F9B4CA`).
+    Upper,
+}
+
+impl Default for Case {
+    fn default() -> Self {
+        Case::Lower
+    }
+}
+
+impl Case {
+    #[inline]
+    const fn table(self) -> &'static [u8; 16] {
+        match self {
+            Case::Lower => HEX_CHARS_LOWER,
+            Case::Upper => HEX_CHARS_UPPER,
+        }
+    }
+}
+
+/// The letter casing found in a hex string by [`detect_case()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Casing {
+    /// All letters are lowercase (e.g. `f9b4ca`).
+    Lower,
+    /// All letters are uppercase (e.g. `F9B4CA`).
+    Upper,
+    /// Both lower- and uppercase letters were found (e.g. `f9B4Ca`).
+    Mixed,
+    /// The string doesn't contain any letters (e.g. `1234`), so it's in
+    /// either case.
+    NoLetters,
+}
+
+impl Casing {
+    /// Returns the [`Case`] to encode with to reproduce the casing, or
+    /// `None` if the casing is mixed.
+    ///
+    /// Strings without letters are reproduced by either case, in which case
+    /// the default [`Case::Lower`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hex::{Case, Casing};
+    ///
+    /// assert_eq!(hex::detect_case("0xDEAD").case(), Some(Case::Upper));
+    /// assert_eq!(Casing::Mixed.case(), None);
+    /// ```
+    pub const fn case(self) -> Option<Case> {
+        match self {
+            Casing::Lower | Casing::NoLetters => Some(Case::Lower),
+            Casing::Upper => Some(Case::Upper),
+            Casing::Mixed => None,
+        }
+    }
+}
+
+/// Reports the letter casing of the hex digits in `data`.
+///
+/// Only the digits `a` to `f` and `A` to `F` are considered, any other
+/// character is ignored. This way, the casing of a prefixed or separated
+/// string (e.g. `0xDEAD` or `de:ad`) is that of its digits, and `data`
+/// doesn't have to be valid hex.
+///
+/// # Example
+///
+/// ```
+/// use hex::Casing;
+///
+/// assert_eq!(hex::detect_case("f9b4ca"), Casing::Lower);
+/// assert_eq!(hex::detect_case("F9B4CA"), Casing::Upper);
+/// assert_eq!(hex::detect_case("f9B4Ca"), Casing::Mixed);
+/// assert_eq!(hex::detect_case("1234"), Casing::NoLetters);
+/// ```
+pub fn detect_case<T: AsRef<[u8]>>(data: T) -> Casing {
+    let mut lower = false;
+    let mut upper = false;
+
+    for &c in data.as_ref() {
+        match c {
+            b'a'..=b'f' => lower = true,
+            b'A'..=b'F' => upper = true,
+            _ => continue,
+        }
+        if lower && upper {
+            return Casing::Mixed;
+        }
+    }
+
+    match (lower, upper) {
+        (true, _) => Casing::Lower,
+        (_, true) => Casing::Upper,
+        _ => Casing::NoLetters,
+    }
 }
 
 const HEX_CHARS_LOWER: &[u8; 16] = b"0123456789abcdef";
 const HEX_CHARS_UPPER: &[u8; 16] = b"0123456789ABCDEF";
 
-struct BytesToHexChars<'a> {
+/// An iterator over the hex digits of some bytes, yielded as ASCII bytes.
+///
+/// Every input byte produces two output bytes, high nibble first. Unlike
+/// [`ToHex`], this yields `u8` instead of `char`, so the output can be fed
+/// directly into byte-oriented sinks such as `Vec<u8>`.
+///
+/// # Example
+///
+/// ```
+/// use hex::HexByteIter;
+///
+/// let mut buf = b"id=".to_vec();
+/// buf.extend(HexByteIter::new(b"kiwi"));
+/// assert_eq!(buf, b"id=6b697769");
+///
+/// assert_eq!(HexByteIter::new(b"kiwi").len(), 8);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HexByteIter<'a> {
     inner: ::core::slice::Iter<'a, u8>,
     table: &'static [u8; 16],
-    next: Option<char>,
+    next: Option<u8>,
+    next_back: Option<u8>,
 }
 
-impl<'a> BytesToHexChars<'a> {
-    fn new(inner: &'a [u8], table: &'static [u8; 16]) -> BytesToHexChars<'a> {
-        BytesToHexChars {
-            inner: inner.iter(),
+impl<'a> HexByteIter<'a> {
+    /// Creates an iterator yielding lowercase hex digits (e.g. `f9b4ca`).
+    pub fn new(data: &'a [u8]) -> HexByteIter<'a> {
+        HexByteIter::with_table(data, HEX_CHARS_LOWER)
+    }
+
+    /// Creates an iterator yielding hex digits using the given letter casing.
+    ///
+    /// ```
+    /// use hex::{Case, HexByteIter};
+    ///
+    /// let digits: Vec<u8> = HexByteIter::with_case(&[0xca, 0xfe], Case::Upper).collect();
+    /// assert_eq!(digits, b"CAFE");
+    /// ```
+    pub fn with_case(data: &'a [u8], case: Case) -> HexByteIter<'a> {
+        HexByteIter::with_table(data, case.table())
+    }
+
+    fn with_table(data: &'a [u8], table: &'static [u8; 16]) -> HexByteIter<'a> {
+        HexByteIter {
+            inner: data.iter(),
             table,
             next: None,
+            next_back: None,
         }
     }
 }
 
-impl Iterator for BytesToHexChars<'_> {
-    type Item = char;
+impl Iterator for HexByteIter<'_> {
+    type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.next.take() {
             Some(current) => Some(current),
-            None => self.inner.next().map(|byte| {
-                let current = self.table[(byte >> 4) as usize] as char;
-                self.next = Some(self.table[(byte & 0x0F) as usize] as char);
-                current
-            }),
+            None => match self.inner.next() {
+                Some(byte) => {
+                    let (current, next) = byte2hex(*byte, self.table);
+                    self.next = Some(next);
+                    Some(current)
+                }
+                None => self.next_back.take(),
+            },
         }
     }
 
@@ -114,19 +436,177 @@ impl Iterator for BytesToHexChars<'_> {
     }
 }
 
-impl iter::ExactSizeIterator for BytesToHexChars<'_> {
+impl iter::DoubleEndedIterator for HexByteIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.next_back.take() {
+            Some(current) => Some(current),
+            None => match self.inner.next_back() {
+                Some(byte) => {
+                    let (next_back, current) = byte2hex(*byte, self.table);
+                    self.next_back = Some(next_back);
+                    Some(current)
+                }
+                None => self.next.take(),
+            },
+        }
+    }
+}
+
+impl iter::ExactSizeIterator for HexByteIter<'_> {
     fn len(&self) -> usize {
         let mut length = self.inner.len() * 2;
         if self.next.is_some() {
             length += 1;
         }
+        if self.next_back.is_some() {
+            length += 1;
+        }
         length
     }
 }
 
+impl iter::FusedIterator for HexByteIter<'_> {}
+
+/// An iterator over the hex digits of some bytes, yielded as `char`s.
+///
+/// This is the iterator behind [`ToHex`]. It encodes lazily, without
+/// allocating, and can be consumed from both ends.
+///
+/// # Example
+///
+/// ```
+/// use hex::BytesToHexChars;
+///
+/// let mut chars = BytesToHexChars::new(b"kiwi");
+/// assert_eq!(chars.next(), Some('6'));
+/// assert_eq!(chars.next_back(), Some('9'));
+/// assert_eq!(chars.len(), 6);
+/// assert_eq!(chars.collect::<String>(), "b69776");
+/// ```
+#[derive(Debug, Clone)]
+pub struct BytesToHexChars<'a> {
+    inner: HexByteIter<'a>,
+}
+
+impl<'a> BytesToHexChars<'a> {
+    /// Creates an iterator yielding lowercase hex digits (e.g. `f9b4ca`).
+    pub fn new(data: &'a [u8]) -> BytesToHexChars<'a> {
+        BytesToHexChars {
+            inner: HexByteIter::new(data),
+        }
+    }
+
+    /// Creates an iterator yielding hex digits using the given letter casing.
+    pub fn with_case(data: &'a [u8], case: Case) -> BytesToHexChars<'a> {
+        BytesToHexChars {
+            inner: HexByteIter::with_case(data, case),
+        }
+    }
+
+    fn with_table(data: &'a [u8], table: &'static [u8; 16]) -> BytesToHexChars<'a> {
+        BytesToHexChars {
+            inner: HexByteIter::with_table(data, table),
+        }
+    }
+}
+
+impl Iterator for BytesToHexChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(char::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl iter::DoubleEndedIterator for BytesToHexChars<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(char::from)
+    }
+}
+
+impl iter::ExactSizeIterator for BytesToHexChars<'_> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl iter::FusedIterator for BytesToHexChars<'_> {}
+
+/// An iterator decoding a hex string lazily, one byte at a time.
+///
+/// Every item is either the next decoded byte, or the error which stopped
+/// decoding, after which the iterator is exhausted. An odd number of digits
+/// is only reported once all whole bytes have been yielded. This allows
+/// streaming consumers to stop early, without decoding the whole input
+/// up front.
+///
+/// # Example
+///
+/// ```
+/// use hex::{DecodeIter, FromHexError};
+///
+/// let mut bytes = DecodeIter::new("6b69g7");
+/// assert_eq!(bytes.next(), Some(Ok(0x6b)));
+/// assert_eq!(bytes.next(), Some(Ok(0x69)));
+/// assert_eq!(
+///     bytes.next(),
+///     Some(Err(FromHexError::InvalidHexCharacter { c: 'g', index: 4 }))
+/// );
+/// assert_eq!(bytes.next(), None);
+///
+/// let sum: Result<u32, _> = DecodeIter::new("0102ff").map(|byte| byte.map(u32::from)).sum();
+/// assert_eq!(sum, Ok(258));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DecodeIter<'a> {
+    data: &'a [u8],
+    index: usize,
+}
+
+impl<'a> DecodeIter<'a> {
+    /// Creates an iterator decoding `data`.
+    pub fn new<T: AsRef<[u8]> + ?Sized>(data: &'a T) -> DecodeIter<'a> {
+        DecodeIter {
+            data: data.as_ref(),
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for DecodeIter<'_> {
+    type Item = Result<u8, FromHexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.index;
+        let byte = match self.data.get(i..)? {
+            [] => return None,
+            [high, low, ..] => val(*high, i).and_then(|high| Ok(high << 4 | val(*low, i + 1)?)),
+            [_] => Err(FromHexError::OddLength),
+        };
+
+        // nothing is decoded after an error
+        self.index = match byte {
+            Ok(_) => i + 2,
+            Err(_) => self.data.len(),
+        };
+        Some(byte)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.data.len() - self.index;
+        (remaining.min(1), Some((remaining + 1) / 2))
+    }
+}
+
+impl iter::FusedIterator for DecodeIter<'_> {}
+
 #[inline]
 fn encode_to_iter<T: iter::FromIterator<char>>(table: &'static [u8; 16], source: &[u8]) -> T {
-    BytesToHexChars::new(source, table).collect()
+    BytesToHexChars::with_table(source, table).collect()
 }
 
 impl<T: AsRef<[u8]>> ToHex for T {
@@ -137,6 +617,14 @@ impl<T: AsRef<[u8]>> ToHex for T {
     fn encode_hex_upper<U: iter::FromIterator<char>>(&self) -> U {
         encode_to_iter(HEX_CHARS_UPPER, self.as_ref())
     }
+
+    fn encode_hex_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        encode_to_fmt_inner(self.as_ref(), w, HEX_CHARS_LOWER)
+    }
+
+    fn encode_hex_upper_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        encode_to_fmt_inner(self.as_ref(), w, HEX_CHARS_UPPER)
+    }
 }
 
 /// Types that can be decoded from a hex string.
@@ -167,12 +655,54 @@ pub trait FromHex: Sized {
     fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error>;
 }
 
-const fn val(c: u8, idx: usize) -> Result<u8, FromHexError> {
+/// Returns the value of a single hex digit, or `None` if `c` isn't a digit.
+///
+/// Both upper and lower case letters are accepted.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::hex_digit_to_value(b'7'), Some(7));
+/// assert_eq!(hex::hex_digit_to_value(b'b'), Some(11));
+/// assert_eq!(hex::hex_digit_to_value(b'B'), Some(11));
+/// assert_eq!(hex::hex_digit_to_value(b'g'), None);
+/// ```
+#[must_use]
+pub const fn hex_digit_to_value(c: u8) -> Option<u8> {
     match c {
-        b'A'..=b'F' => Ok(c - b'A' + 10),
-        b'a'..=b'f' => Ok(c - b'a' + 10),
-        b'0'..=b'9' => Ok(c - b'0'),
-        _ => Err(FromHexError::InvalidHexCharacter {
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'0'..=b'9' => Some(c - b'0'),
+        _ => None,
+    }
+}
+
+/// Returns the hex digit for a value in the range `0..16`, using the given
+/// letter casing.
+///
+/// # Panics
+///
+/// Panics if `value` is 16 or larger.
+///
+/// # Example
+///
+/// ```
+/// use hex::Case;
+///
+/// assert_eq!(hex::value_to_hex_digit(7, Case::Lower), b'7');
+/// assert_eq!(hex::value_to_hex_digit(11, Case::Lower), b'b');
+/// assert_eq!(hex::value_to_hex_digit(11, Case::Upper), b'B');
+/// ```
+#[must_use]
+pub const fn value_to_hex_digit(value: u8, case: Case) -> u8 {
+    assert!(value < 16, "value of a hex digit must be less than 16");
+    case.table()[value as usize]
+}
+
+const fn val(c: u8, idx: usize) -> Result<u8, FromHexError> {
+    match hex_digit_to_value(c) {
+        Some(value) => Ok(value),
+        None => Err(FromHexError::InvalidHexCharacter {
             c: c as char,
             index: idx,
         }),
@@ -185,14 +715,56 @@ impl FromHex for Vec<u8> {
 
     fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
         let hex = hex.as_ref();
-        if hex.len() % 2 != 0 {
-            return Err(FromHexError::OddLength);
-        }
+        let len = decoded_len(hex.len())?;
+
+        let mut out = Vec::with_capacity(len);
+        decode_to_uninit_slice(hex, &mut out.spare_capacity_mut()[..len])?;
+        // SAFETY: the first `len` elements have just been initialized.
+        unsafe { out.set_len(len) };
+        Ok(out)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromHex for Box<[u8]> {
+    type Error = FromHexError;
+
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        // the vector is allocated with the exact length, so this doesn't
+        // reallocate
+        Vec::from_hex(hex).map(Vec::into_boxed_slice)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromHex for Cow<'_, [u8]> {
+    type Error = FromHexError;
+
+    /// Decodes into [`Cow::Owned`], as hex never borrows from its input.
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        Vec::from_hex(hex).map(Cow::Owned)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromHex for VecDeque<u8> {
+    type Error = FromHexError;
+
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        // recent versions of std reuse the vector's buffer, without copying
+        Vec::from_hex(hex).map(VecDeque::from)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromHex for String {
+    type Error = FromHexError;
 
-        hex.chunks(2)
-            .enumerate()
-            .map(|(i, pair)| Ok(val(pair[0], 2 * i)? << 4 | val(pair[1], 2 * i + 1)?))
-            .collect()
+    /// Decodes hex-encoded UTF-8 text, see [`decode_utf8()`].
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        String::from_utf8(Vec::from_hex(hex)?).map_err(|err| FromHexError::InvalidUtf8 {
+            valid_up_to: err.utf8_error().valid_up_to(),
+        })
     }
 }
 
@@ -207,39 +779,414 @@ impl<const N: usize> FromHex for [u8; N] {
     }
 }
 
-/// Encodes `data` as hex string using lowercase characters.
+/// Types that can be decoded from an iterator over hex digits.
 ///
-/// Lowercase characters are used (e.g. `f9b4ca`). The resulting string's
-/// length is always even, each byte in `data` is always encoded using two hex
-/// digits. Thus, the resulting string contains exactly twice as many bytes as
-/// the input data.
+/// This is the companion of [`FromHex`] for input which isn't available as
+/// one contiguous slice, e.g. the output of a tokenizer or `str::chars()`.
+/// The digits may be given as `u8` or `char`, positions reported in errors
+/// count the items of the iterator.
 ///
 /// # Example
 ///
 /// ```
-/// assert_eq!(hex::encode("Hello world!"), "48656c6c6f20776f726c6421");
-/// assert_eq!(hex::encode(vec![1, 2, 3, 15, 16]), "0102030f10");
-/// ```
-#[must_use]
-#[cfg(feature = "alloc")]
-pub fn encode<T: AsRef<[u8]>>(data: T) -> String {
-    data.encode_hex()
-}
-
-/// Encodes `data` as hex string using uppercase characters.
-///
-/// Apart from the characters' casing, this works exactly like `encode()`.
+/// use hex::FromHexIter;
 ///
-/// # Example
+/// let text = "key: 6b 69 77 69";
+/// let digits = text.chars().skip(5).filter(|c| !c.is_whitespace());
+/// assert_eq!(<[u8; 4]>::from_hex_iter(digits), Ok(*b"kiwi"));
 ///
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(Vec::from_hex_iter(b"6b69".iter().copied()), Ok(b"ki".to_vec()));
 /// ```
-/// assert_eq!(hex::encode_upper("Hello world!"), "48656C6C6F20776F726C6421");
-/// assert_eq!(hex::encode_upper(vec![1, 2, 3, 15, 16]), "0102030F10");
-/// ```
-#[must_use]
-#[cfg(feature = "alloc")]
-pub fn encode_upper<T: AsRef<[u8]>>(data: T) -> String {
-    data.encode_hex_upper()
+pub trait FromHexIter: Sized {
+    type Error;
+
+    /// Creates an instance of type `Self` from the given hex digits, or fails
+    /// with a custom error type.
+    fn from_hex_iter<I>(digits: I) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<char>;
+}
+
+// decodes the `digits`, passing the index and value of every decoded byte to
+// `push`. Returns the number of digits.
+fn decode_digits_with<I, F>(digits: I, mut push: F) -> Result<usize, FromHexError>
+where
+    I: IntoIterator,
+    I::Item: Into<char>,
+    F: FnMut(usize, u8) -> Result<(), FromHexError>,
+{
+    let mut count = 0;
+    let mut high = 0;
+
+    for (i, c) in digits.into_iter().enumerate() {
+        let c = c.into();
+        let nibble = match c.to_digit(16) {
+            Some(nibble) => nibble as u8,
+            None => return Err(FromHexError::InvalidHexCharacter { c, index: i }),
+        };
+
+        if i % 2 == 0 {
+            high = nibble;
+        } else {
+            push(i / 2, high << 4 | nibble)?;
+        }
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+#[cfg(feature = "alloc")]
+impl FromHexIter for Vec<u8> {
+    type Error = FromHexError;
+
+    fn from_hex_iter<I>(digits: I) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<char>,
+    {
+        let digits = digits.into_iter();
+        let mut out = Vec::with_capacity(digits.size_hint().0 / 2);
+
+        let count = decode_digits_with(digits, |_, byte| {
+            out.push(byte);
+            Ok(())
+        })?;
+        decoded_len(count)?;
+        Ok(out)
+    }
+}
+
+impl<const N: usize> FromHexIter for [u8; N] {
+    type Error = FromHexError;
+
+    fn from_hex_iter<I>(digits: I) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<char>,
+    {
+        let mut out = [0_u8; N];
+
+        let count = decode_digits_with(digits, |i, byte| {
+            let slot = out.get_mut(i).ok_or(FromHexError::InvalidStringLength)?;
+            *slot = byte;
+            Ok(())
+        })?;
+        if decoded_len(count)? != N {
+            return Err(FromHexError::InvalidStringLength);
+        }
+        Ok(out)
+    }
+}
+
+/// Encodes `data` as hex string using lowercase characters.
+///
+/// Lowercase characters are used (e.g. `f9b4ca`). The resulting string's
+/// length is always even, each byte in `data` is always encoded using two hex
+/// digits. Thus, the resulting string contains exactly twice as many bytes as
+/// the input data.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::encode("Hello world!"), "48656c6c6f20776f726c6421");
+/// assert_eq!(hex::encode(vec![1, 2, 3, 15, 16]), "0102030f10");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode<T: AsRef<[u8]>>(data: T) -> String {
+    data.encode_hex()
+}
+
+/// Encodes `data` as hex string using uppercase characters.
+///
+/// Apart from the characters' casing, this works exactly like `encode()`.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::encode_upper("Hello world!"), "48656C6C6F20776F726C6421");
+/// assert_eq!(hex::encode_upper(vec![1, 2, 3, 15, 16]), "0102030F10");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_upper<T: AsRef<[u8]>>(data: T) -> String {
+    data.encode_hex_upper()
+}
+
+/// Encodes `data` as hex string using the letter casing selected by `case`.
+///
+/// This works exactly like [`encode()`] or [`encode_upper()`], depending on
+/// `case`.
+///
+/// # Example
+///
+/// ```
+/// use hex::Case;
+///
+/// assert_eq!(hex::encode_with_case("kiwi", Case::Lower), "6b697769");
+/// assert_eq!(hex::encode_with_case([0xff, 0x0a], Case::Upper), "FF0A");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_with_case<T: AsRef<[u8]>>(data: T, case: Case) -> String {
+    data.encode_hex_case(case)
+}
+
+/// Encodes `data` as `0x`-prefixed hex string using lowercase characters.
+///
+/// Apart from the prefix, this works exactly like [`encode()`].
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::encode_prefixed([0xde, 0xad, 0xbe, 0xef]), "0xdeadbeef");
+/// assert_eq!(hex::encode_prefixed([]), "0x");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_prefixed<T: AsRef<[u8]>>(data: T) -> String {
+    encode_prefixed_inner(data.as_ref(), HEX_CHARS_LOWER)
+}
+
+/// Encodes `data` as `0x`-prefixed hex string using uppercase characters.
+///
+/// The prefix itself always uses a lowercase `x`, apart from that this works
+/// exactly like [`encode_upper()`].
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::encode_upper_prefixed([0xde, 0xad, 0xbe, 0xef]), "0xDEADBEEF");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_upper_prefixed<T: AsRef<[u8]>>(data: T) -> String {
+    encode_prefixed_inner(data.as_ref(), HEX_CHARS_UPPER)
+}
+
+#[cfg(feature = "alloc")]
+fn encode_prefixed_inner(data: &[u8], table: &'static [u8; 16]) -> String {
+    let mut buf = String::with_capacity(2 + data.len() * 2);
+    buf.push_str("0x");
+    encode_append_inner(&mut buf, data, table);
+    buf
+}
+
+/// Encodes several byte slices into one hex string using lowercase
+/// characters.
+///
+/// The total length is computed up front, so all parts are encoded into a
+/// single allocation. This requires the iterator to be `Clone`, which is the
+/// case when iterating over a borrowed collection.
+///
+/// # Example
+///
+/// ```
+/// let header = [0x01, 0x02];
+/// let payload = b"kiwi".to_vec();
+///
+/// assert_eq!(hex::encode_many([&header[..], &payload]), "01026b697769");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_many<I>(parts: I) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+    I::IntoIter: Clone,
+{
+    encode_many_inner(parts.into_iter(), "")
+}
+
+/// Encodes several byte slices into one hex string using lowercase
+/// characters, inserting `separator` between the parts.
+///
+/// Apart from the separator, this works exactly like [`encode_many()`].
+///
+/// # Example
+///
+/// ```
+/// let keys = vec![vec![0xde, 0xad], vec![0xbe, 0xef], vec![]];
+///
+/// assert_eq!(hex::encode_many_with_separator(&keys, ','), "dead,beef,");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_many_with_separator<I>(parts: I, separator: char) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+    I::IntoIter: Clone,
+{
+    let mut buf = [0_u8; 4];
+    encode_many_inner(parts.into_iter(), separator.encode_utf8(&mut buf))
+}
+
+#[cfg(feature = "alloc")]
+fn encode_many_inner<I>(parts: I, separator: &str) -> String
+where
+    I: Iterator + Clone,
+    I::Item: AsRef<[u8]>,
+{
+    let (count, len) = parts.clone().fold((0_usize, 0), |(count, len), part| {
+        (count + 1, len + part.as_ref().len())
+    });
+
+    let separators = separator.len() * count.saturating_sub(1);
+    let mut buf = String::with_capacity(len * 2 + separators);
+
+    for (i, part) in parts.enumerate() {
+        if i > 0 {
+            buf.push_str(separator);
+        }
+        encode_append_inner(&mut buf, part.as_ref(), HEX_CHARS_LOWER);
+    }
+
+    buf
+}
+
+/// Replaces the bytes in `buf` with their hex representation using lowercase
+/// characters.
+///
+/// The vector is grown to twice its length and rewritten back to front, so no
+/// second buffer is allocated when the original bytes aren't needed anymore.
+///
+/// # Example
+///
+/// ```
+/// let mut buf = b"kiwi".to_vec();
+/// hex::encode_in_vec(&mut buf);
+/// assert_eq!(buf, b"6b697769");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_in_vec(buf: &mut Vec<u8>) {
+    encode_in_vec_inner(buf, HEX_CHARS_LOWER);
+}
+
+/// Replaces the bytes in `buf` with their hex representation using uppercase
+/// characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_in_vec()`].
+///
+/// # Example
+///
+/// ```
+/// let mut buf = vec![0xca, 0xfe];
+/// hex::encode_upper_in_vec(&mut buf);
+/// assert_eq!(buf, b"CAFE");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_upper_in_vec(buf: &mut Vec<u8>) {
+    encode_in_vec_inner(buf, HEX_CHARS_UPPER);
+}
+
+#[cfg(feature = "alloc")]
+fn encode_in_vec_inner(buf: &mut Vec<u8>, table: &[u8; 16]) {
+    let len = buf.len();
+    buf.resize(len * 2, 0);
+
+    // going back to front never overwrites a byte which wasn't encoded yet
+    for i in (0..len).rev() {
+        let (high, low) = byte2hex(buf[i], table);
+        buf[2 * i] = high;
+        buf[2 * i + 1] = low;
+    }
+}
+
+/// Encodes the bytes yielded by `data` as hex string using lowercase
+/// characters.
+///
+/// This works like [`encode()`], but accepts any source of bytes, so data
+/// living in non-contiguous structures doesn't need to be copied into a
+/// `Vec<u8>` first.
+///
+/// # Example
+///
+/// ```
+/// let packets: [&[u8]; 2] = [b"\x01\x02", b"\xff"];
+/// let bytes = packets.iter().flat_map(|packet| packet.iter().copied());
+///
+/// assert_eq!(hex::encode_iter(bytes), "0102ff");
+/// assert_eq!(hex::encode_iter(0..4), "00010203");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_iter<I: IntoIterator<Item = u8>>(data: I) -> String {
+    encode_iter_inner(data.into_iter(), HEX_CHARS_LOWER)
+}
+
+/// Encodes the bytes yielded by `data` as hex string using uppercase
+/// characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_iter()`].
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::encode_upper_iter(vec![0xca, 0xfe]), "CAFE");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_upper_iter<I: IntoIterator<Item = u8>>(data: I) -> String {
+    encode_iter_inner(data.into_iter(), HEX_CHARS_UPPER)
+}
+
+#[cfg(feature = "alloc")]
+fn encode_iter_inner<I: Iterator<Item = u8>>(data: I, table: &[u8; 16]) -> String {
+    let mut buf = String::with_capacity(data.size_hint().0 * 2);
+    for byte in data {
+        let (high, low) = byte2hex(byte, table);
+        buf.push(high as char);
+        buf.push(low as char);
+    }
+    buf
+}
+
+/// Appends the hex representation of `data` to `buf` using lowercase
+/// characters.
+///
+/// This works like [`encode()`], but writes into an existing `String` instead
+/// of allocating a new one.
+///
+/// # Example
+///
+/// ```
+/// let mut buf = String::from("id=");
+/// hex::encode_append(&mut buf, [0x0f, 0xab]);
+/// assert_eq!(buf, "id=0fab");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_append<T: AsRef<[u8]>>(buf: &mut String, data: T) {
+    encode_append_inner(buf, data.as_ref(), HEX_CHARS_LOWER);
+}
+
+/// Appends the hex representation of `data` to `buf` using uppercase
+/// characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_append()`].
+///
+/// # Example
+///
+/// ```
+/// let mut buf = String::from("id=");
+/// hex::encode_upper_append(&mut buf, [0x0f, 0xab]);
+/// assert_eq!(buf, "id=0FAB");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_upper_append<T: AsRef<[u8]>>(buf: &mut String, data: T) {
+    encode_append_inner(buf, data.as_ref(), HEX_CHARS_UPPER);
+}
+
+#[cfg(feature = "alloc")]
+fn encode_append_inner(buf: &mut String, data: &[u8], table: &'static [u8; 16]) {
+    buf.reserve(data.len() * 2);
+    buf.extend(BytesToHexChars::with_table(data, table));
 }
 
 /// Decodes a hex string into raw bytes.
@@ -263,175 +1210,1875 @@ pub fn decode<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
     FromHex::from_hex(data)
 }
 
-/// Decode a hex string into a mutable bytes slice.
-///
-/// Both, upper and lower case characters are valid in the input string and can
-/// even be mixed (e.g. `f9b4ca`, `F9B4CA` and `f9B4Ca` are all valid strings).
-///
-/// # Example
-///
-/// ```
-/// let mut bytes = [0u8; 4];
-/// assert_eq!(hex::decode_to_slice("6b697769", &mut bytes as &mut [u8]), Ok(()));
-/// assert_eq!(&bytes, b"kiwi");
-/// ```
-pub fn decode_to_slice<T: AsRef<[u8]>>(data: T, out: &mut [u8]) -> Result<(), FromHexError> {
-    let data = data.as_ref();
+/// Decodes a hex string into raw bytes, unless it would decode to more than
+/// `max_len` bytes.
+///
+/// The length is checked before anything is allocated, which makes this
+/// suitable for untrusted input. Apart from that, this works exactly like
+/// [`decode()`].
+///
+/// # Errors
+///
+/// Returns [`InvalidStringLength`](FromHexError::InvalidStringLength) if the
+/// input is longer than `2 * max_len` characters, and the errors of
+/// [`decode()`] otherwise.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::decode_capped("6b697769", 4), Ok(b"kiwi".to_vec()));
+/// assert_eq!(hex::decode_capped("6b697769", 3), Err(FromHexError::InvalidStringLength));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_capped<T: AsRef<[u8]>>(data: T, max_len: usize) -> Result<Vec<u8>, FromHexError> {
+    let data = data.as_ref();
+    if data.len() / 2 > max_len {
+        return Err(FromHexError::InvalidStringLength);
+    }
+    decode(data)
+}
+
+/// Decodes a hex string and appends the raw bytes to `buf`.
+///
+/// This works like [`decode()`], but writes into an existing `Vec` instead of
+/// allocating a new one, reserving exactly the space needed. If decoding
+/// fails, `buf` is left unchanged.
+///
+/// # Example
+///
+/// ```
+/// let mut buf = b"id=".to_vec();
+/// assert_eq!(hex::decode_append(&mut buf, "0fab"), Ok(()));
+/// assert_eq!(buf, b"id=\x0f\xab");
+///
+/// assert!(hex::decode_append(&mut buf, "0fa").is_err());
+/// assert_eq!(buf, b"id=\x0f\xab");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_append<T: AsRef<[u8]>>(buf: &mut Vec<u8>, data: T) -> Result<(), FromHexError> {
+    let data = data.as_ref();
+    let len = decoded_len(data.len())?;
+
+    buf.reserve_exact(len);
+    decode_to_uninit_slice(data, &mut buf.spare_capacity_mut()[..len])?;
+    // SAFETY: the `len` elements after the current end have just been
+    // initialized.
+    unsafe { buf.set_len(buf.len() + len) };
+    Ok(())
+}
+
+/// Replaces the hex string in `buf` with the raw bytes it encodes.
+///
+/// The bytes are decoded front to back into the same buffer, which is then
+/// truncated to half its length, so no second buffer is allocated. This is
+/// the inverse of [`encode_in_vec()`]. If decoding fails, `buf` is left
+/// unchanged.
+///
+/// # Example
+///
+/// ```
+/// let mut buf = b"6b697769".to_vec();
+/// assert_eq!(hex::decode_vec_in_place(&mut buf), Ok(()));
+/// assert_eq!(buf, b"kiwi");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_vec_in_place(buf: &mut Vec<u8>) -> Result<(), FromHexError> {
+    let len = decode_in_slice(buf)?.len();
+    buf.truncate(len);
+    Ok(())
+}
+
+/// Decodes a hex string into a `String`, for hex-encoded text.
+///
+/// # Errors
+///
+/// Apart from the errors of [`decode()`], returns
+/// [`InvalidUtf8`](FromHexError::InvalidUtf8) if the decoded bytes aren't
+/// valid UTF-8.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::decode_utf8("6b697769"), Ok("kiwi".to_string()));
+/// assert_eq!(
+///     hex::decode_utf8("6b69ff69"),
+///     Err(FromHexError::InvalidUtf8 { valid_up_to: 2 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_utf8<T: AsRef<[u8]>>(data: T) -> Result<String, FromHexError> {
+    FromHex::from_hex(data)
+}
+
+/// Decodes a hex string into a boxed slice of raw bytes.
+///
+/// The slice is allocated with exactly the decoded length, which makes this
+/// suitable for storing immutable blobs. Apart from that, this works exactly
+/// like [`decode()`].
+///
+/// # Example
+///
+/// ```
+/// let blob: Box<[u8]> = hex::decode_to_boxed_slice("6b697769").unwrap();
+/// assert_eq!(&*blob, b"kiwi");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_to_boxed_slice<T: AsRef<[u8]>>(data: T) -> Result<Box<[u8]>, FromHexError> {
+    FromHex::from_hex(data)
+}
+
+/// Encodes `data` in reverse byte order as hex string using lowercase
+/// characters.
+///
+/// The last byte of `data` is encoded first. This is how e.g. Bitcoin
+/// transaction ids and many hash displays are rendered. The digits of each
+/// byte keep their usual order.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::encode_reverse([0x01, 0x02, 0xab]), "ab0201");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_reverse<T: AsRef<[u8]>>(data: T) -> String {
+    encode_iter_inner(data.as_ref().iter().rev().copied(), HEX_CHARS_LOWER)
+}
+
+/// Encodes `data` in reverse byte order as hex string using uppercase
+/// characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_reverse()`].
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::encode_upper_reverse([0x01, 0x02, 0xab]), "AB0201");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_upper_reverse<T: AsRef<[u8]>>(data: T) -> String {
+    encode_iter_inner(data.as_ref().iter().rev().copied(), HEX_CHARS_UPPER)
+}
+
+/// Encodes `data` as hex string with the nibbles of every byte swapped, using
+/// lowercase characters.
+///
+/// The low nibble of each byte is encoded first. This is the semi-octet
+/// representation used by telecom protocols (e.g. GSM TS 23.040 addresses and
+/// SIM files) to store digit strings.
+///
+/// # Example
+///
+/// ```
+/// // the phone number 1234567 in semi-octets, padded with `f`
+/// let number = [0x21, 0x43, 0x65, 0xf7];
+///
+/// assert_eq!(hex::encode_swapped(number), "1234567f");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_swapped<T: AsRef<[u8]>>(data: T) -> String {
+    let bytes = data.as_ref().iter().map(|byte| byte.rotate_left(4));
+    encode_iter_inner(bytes, HEX_CHARS_LOWER)
+}
+
+/// Encodes `data` as hex string with the nibbles of every byte swapped, using
+/// uppercase characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_swapped()`].
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::encode_upper_swapped([0x21, 0x43, 0x65, 0xf7]), "1234567F");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_upper_swapped<T: AsRef<[u8]>>(data: T) -> String {
+    let bytes = data.as_ref().iter().map(|byte| byte.rotate_left(4));
+    encode_iter_inner(bytes, HEX_CHARS_UPPER)
+}
+
+/// Decodes a hex string with swapped nibbles into raw bytes.
+///
+/// This is the inverse of [`encode_swapped()`]: the first digit of every pair
+/// becomes the low nibble of the decoded byte. Error positions refer to the
+/// input string, just like with [`decode()`].
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::decode_swapped("1234567f"), Ok(vec![0x21, 0x43, 0x65, 0xf7]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_swapped<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    let mut bytes = decode(data)?;
+    for byte in &mut bytes {
+        *byte = byte.rotate_left(4);
+    }
+    Ok(bytes)
+}
+
+/// Encodes the first `nibbles` nibbles of `data` as hex string using
+/// lowercase characters.
+///
+/// Some protocols express data as an odd number of nibbles, in which case the
+/// low nibble of the last byte is not part of the output. Returns an error if
+/// `data` doesn't contain that many nibbles.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::encode_nibbles([0xab, 0xc0], 3), Ok("abc".to_owned()));
+/// assert_eq!(hex::encode_nibbles([0xab, 0xc0], 4), Ok("abc0".to_owned()));
+/// assert!(hex::encode_nibbles([0xab, 0xc0], 5).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_nibbles<T: AsRef<[u8]>>(data: T, nibbles: usize) -> Result<String, FromHexError> {
+    let data = data.as_ref();
+    if nibbles > data.len() * 2 {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    Ok(BytesToHexChars::new(data).take(nibbles).collect())
+}
+
+/// Decodes a hex string with a possibly odd number of digits into raw bytes.
+///
+/// Instead of failing with [`FromHexError::OddLength`], a trailing single digit
+/// is decoded into the high nibble of the last byte, leaving its low nibble
+/// zero. Returns the decoded bytes together with the number of nibbles, which
+/// is needed to encode the data again with [`encode_nibbles()`].
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::decode_nibbles("abc"), Ok((vec![0xab, 0xc0], 3)));
+/// assert_eq!(hex::decode_nibbles("abcd"), Ok((vec![0xab, 0xcd], 4)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_nibbles<T: AsRef<[u8]>>(data: T) -> Result<(Vec<u8>, usize), FromHexError> {
+    let data = data.as_ref();
+    let mut out = alloc::vec![0; (data.len() + 1) / 2];
+    decode_nibbles_to_slice(data, &mut out)?;
+    Ok((out, data.len()))
+}
+
+/// Decodes a hex string into raw bytes in reverse byte order.
+///
+/// This is the inverse of [`encode_reverse()`]: the first two digits of the
+/// input make up the last byte of the output. Error positions refer to the
+/// input string, just like with [`decode()`].
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::decode_reverse("ab0201"), Ok(vec![0x01, 0x02, 0xab]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_reverse<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    let mut bytes = decode(data)?;
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// Decode a hex string into a mutable bytes slice.
+///
+/// Both, upper and lower case characters are valid in the input string and can
+/// even be mixed (e.g. `f9b4ca`, `F9B4CA` and `f9B4Ca` are all valid strings).
+///
+/// # Example
+///
+/// ```
+/// let mut bytes = [0u8; 4];
+/// assert_eq!(hex::decode_to_slice("6b697769", &mut bytes as &mut [u8]), Ok(()));
+/// assert_eq!(&bytes, b"kiwi");
+/// ```
+pub fn decode_to_slice<T: AsRef<[u8]>>(data: T, out: &mut [u8]) -> Result<(), FromHexError> {
+    let data = data.as_ref();
+
+    if data.len() % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+    if data.len() / 2 != out.len() {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = val(data[2 * i], 2 * i)? << 4 | val(data[2 * i + 1], 2 * i + 1)?;
+    }
+
+    Ok(())
+}
+
+/// Decodes a hex string into a byte array of length `N`.
+///
+/// This is a shorthand for [`<[u8; N]>::from_hex`](FromHex::from_hex), e.g.
+/// for decoding hashes of a known length. The length is usually inferred,
+/// otherwise it can be given as `decode_to_array::<N, _>`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode_to_slice()`], in particular
+/// [`InvalidStringLength`](FromHexError::InvalidStringLength) if the input
+/// doesn't consist of exactly `2 * N` digits.
+///
+/// # Example
+///
+/// ```
+/// let hash: [u8; 2] = hex::decode_to_array("beef").unwrap();
+/// assert_eq!(hash, [0xbe, 0xef]);
+///
+/// let hash = hex::decode_to_array::<4, _>("6b697769");
+/// assert_eq!(hash, Ok(*b"kiwi"));
+/// ```
+pub fn decode_to_array<const N: usize, T: AsRef<[u8]>>(data: T) -> Result<[u8; N], FromHexError> {
+    FromHex::from_hex(data)
+}
+
+/// Decode a hex string into the beginning of a mutable bytes slice,
+/// returning the number of decoded bytes.
+///
+/// Unlike [`decode_to_slice()`], the output buffer may be larger than
+/// needed, which makes it easy to reuse a single buffer for inputs of
+/// different lengths. The rest of the buffer is left untouched.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode_to_slice()`], and
+/// [`InvalidStringLength`](FromHexError::InvalidStringLength) if `out` is
+/// too small.
+///
+/// # Example
+///
+/// ```
+/// let mut buf = [0u8; 16];
+/// assert_eq!(hex::decode_to_slice_partial("6b697769", &mut buf), Ok(4));
+/// assert_eq!(&buf[..4], b"kiwi");
+/// ```
+pub fn decode_to_slice_partial<T: AsRef<[u8]>>(
+    data: T,
+    out: &mut [u8],
+) -> Result<usize, FromHexError> {
+    let data = data.as_ref();
+    let len = decoded_len(data.len())?;
+    let out = out
+        .get_mut(..len)
+        .ok_or(FromHexError::InvalidStringLength)?;
+
+    decode_to_slice(data, out)?;
+    Ok(len)
+}
+
+/// Decode a hex string into a mutable bytes slice, without checking the
+/// digits.
+///
+/// This is a faster variant of [`decode_to_slice()`] for input which has
+/// already been validated, e.g. by [`validate()`] or a protocol parser.
+/// Valid input is decoded exactly like [`decode_to_slice()`] does. Invalid
+/// characters aren't reported though, but decoded to an unspecified value
+/// instead. This never causes undefined behaviour, only wrong output.
+///
+/// # Errors
+///
+/// Only the length of the input is checked, returning
+/// [`OddLength`](FromHexError::OddLength) and
+/// [`InvalidStringLength`](FromHexError::InvalidStringLength) just like
+/// [`decode_to_slice()`].
+///
+/// # Example
+///
+/// ```
+/// let mut bytes = [0u8; 4];
+/// assert_eq!(hex::decode_to_slice_unchecked("6b697769", &mut bytes), Ok(()));
+/// assert_eq!(&bytes, b"kiwi");
+/// ```
+pub fn decode_to_slice_unchecked<T: AsRef<[u8]>>(
+    data: T,
+    out: &mut [u8],
+) -> Result<(), FromHexError> {
+    let data = data.as_ref();
+
+    if data.len() % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+    if data.len() / 2 != out.len() {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    for (byte, pair) in out.iter_mut().zip(data.chunks_exact(2)) {
+        *byte = val_unchecked(pair[0]) << 4 | val_unchecked(pair[1]);
+    }
+
+    Ok(())
+}
+
+/// Decode a hex string into a slice of possibly uninitialized bytes.
+///
+/// This works like [`decode_to_slice()`], but doesn't require the output to be
+/// initialized (e.g. zero-filled) up front. On success the now initialized
+/// output is returned.
+///
+/// The output buffer has to be able to hold exactly `data.len() / 2` bytes,
+/// otherwise this function will return an error.
+///
+/// # Example
+///
+/// ```
+/// use core::mem::MaybeUninit;
+///
+/// # fn main() -> Result<(), hex::FromHexError> {
+/// let mut buf = [MaybeUninit::uninit(); 4];
+///
+/// assert_eq!(hex::decode_to_uninit_slice("6b697769", &mut buf)?, b"kiwi");
+/// # Ok(())
+/// # }
+/// ```
+pub fn decode_to_uninit_slice<T: AsRef<[u8]>>(
+    data: T,
+    out: &mut [MaybeUninit<u8>],
+) -> Result<&mut [u8], FromHexError> {
+    let data = data.as_ref();
+
+    if data.len() % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+    if data.len() / 2 != out.len() {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = MaybeUninit::new(val(data[2 * i], 2 * i)? << 4 | val(data[2 * i + 1], 2 * i + 1)?);
+    }
+
+    // SAFETY: every element of `out` has been initialized above, and
+    // `MaybeUninit<u8>` is guaranteed to have the same layout as `u8`.
+    Ok(unsafe { &mut *(out as *mut [MaybeUninit<u8>] as *mut [u8]) })
+}
+
+/// Decodes the hex string in `buf` into the first half of the same buffer,
+/// returning the decoded bytes.
+///
+/// The returned slice is the first `buf.len() / 2` bytes of `buf`. The second
+/// half of the buffer still contains the end of the hex string afterwards.
+/// If decoding fails, `buf` is left unchanged.
+///
+/// # Example
+///
+/// ```
+/// let mut buf = *b"6b697769";
+/// let decoded = hex::decode_in_slice(&mut buf).unwrap();
+/// assert_eq!(decoded, b"kiwi");
+/// ```
+pub fn decode_in_slice(buf: &mut [u8]) -> Result<&mut [u8], FromHexError> {
+    // check everything first, so nothing is overwritten on errors
+    validate(&*buf)?;
+
+    let len = buf.len() / 2;
+    // going front to back never overwrites a digit which wasn't decoded yet
+    for i in 0..len {
+        buf[i] = val_unchecked(buf[2 * i]) << 4 | val_unchecked(buf[2 * i + 1]);
+    }
+    Ok(&mut buf[..len])
+}
+
+/// Decodes the hex string in `buf` into the first half of the same buffer,
+/// zeroing the second half.
+///
+/// Apart from overwriting what's left of the hex string with zeros after
+/// decoding succeeded, this works exactly like [`decode_in_slice()`]. This
+/// way no copy of decoded secrets stays around in the buffer.
+///
+/// # Example
+///
+/// ```
+/// let mut buf = *b"6b697769";
+/// let decoded = hex::decode_in_slice_zeroing(&mut buf).unwrap();
+/// assert_eq!(decoded, b"kiwi");
+/// assert_eq!(buf, *b"kiwi\0\0\0\0");
+/// ```
+pub fn decode_in_slice_zeroing(buf: &mut [u8]) -> Result<&mut [u8], FromHexError> {
+    let len = decode_in_slice(buf)?.len();
+    let (decoded, rest) = buf.split_at_mut(len);
+    rest.fill(0);
+    Ok(decoded)
+}
+
+// the value of the digit `c` without any branches, letters have bit 6 set
+// and their low nibble counts from 1.
+#[inline]
+const fn val_unchecked(c: u8) -> u8 {
+    ((c & 0x0f) + 9 * (c >> 6)) & 0x0f
+}
+
+/// Decode a hex string with a possibly odd number of digits into a mutable
+/// bytes slice.
+///
+/// The output buffer has to be able to hold exactly `(data.len() + 1) / 2`
+/// bytes. Apart from that, this works like [`decode_nibbles()`].
+///
+/// # Example
+///
+/// ```
+/// let mut bytes = [0u8; 2];
+/// assert_eq!(hex::decode_nibbles_to_slice("123", &mut bytes), Ok(()));
+/// assert_eq!(bytes, [0x12, 0x30]);
+/// ```
+pub fn decode_nibbles_to_slice<T: AsRef<[u8]>>(
+    data: T,
+    out: &mut [u8],
+) -> Result<(), FromHexError> {
+    let data = data.as_ref();
+
+    if (data.len() + 1) / 2 != out.len() {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    let (pairs, rest) = data.split_at(data.len() & !1);
+    let (bytes, last) = out.split_at_mut(pairs.len() / 2);
+    decode_to_slice(pairs, bytes)?;
+
+    if let (Some(c), Some(byte)) = (rest.first(), last.first_mut()) {
+        *byte = val(*c, pairs.len())? << 4;
+    }
+
+    Ok(())
+}
+
+/// Decode a hex string with swapped nibbles into a mutable bytes slice.
+///
+/// Apart from swapping the nibbles of every byte, this works exactly like
+/// [`decode_to_slice()`].
+///
+/// # Example
+///
+/// ```
+/// let mut bytes = [0u8; 2];
+/// assert_eq!(hex::decode_swapped_to_slice("214f", &mut bytes), Ok(()));
+/// assert_eq!(bytes, [0x12, 0xf4]);
+/// ```
+pub fn decode_swapped_to_slice<T: AsRef<[u8]>>(
+    data: T,
+    out: &mut [u8],
+) -> Result<(), FromHexError> {
+    decode_to_slice(data, out)?;
+    for byte in out {
+        *byte = byte.rotate_left(4);
+    }
+    Ok(())
+}
+
+/// Decode a hex string into a mutable bytes slice in reverse byte order.
+///
+/// Apart from the byte order of the output, this works exactly like
+/// [`decode_to_slice()`].
+///
+/// # Example
+///
+/// ```
+/// let mut txid = [0u8; 4];
+/// assert_eq!(hex::decode_reverse_to_slice("6977696b", &mut txid), Ok(()));
+/// assert_eq!(&txid, b"kiwi");
+/// ```
+pub fn decode_reverse_to_slice<T: AsRef<[u8]>>(
+    data: T,
+    out: &mut [u8],
+) -> Result<(), FromHexError> {
+    decode_to_slice(data, out)?;
+    out.reverse();
+    Ok(())
+}
+
+/// Checks whether `data` is a valid hex string, without decoding it.
+///
+/// This accepts exactly the strings accepted by [`decode()`], and returns
+/// the same errors, but neither allocates nor writes any output.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::validate("6b697769"), Ok(()));
+/// assert_eq!(hex::validate("6b69776"), Err(FromHexError::OddLength));
+/// assert_eq!(
+///     hex::validate("6b69776g"),
+///     Err(FromHexError::InvalidHexCharacter { c: 'g', index: 7 })
+/// );
+/// ```
+pub fn validate<T: AsRef<[u8]>>(data: T) -> Result<(), FromHexError> {
+    let data = data.as_ref();
+
+    if data.len() % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+
+    for (i, &c) in data.iter().enumerate() {
+        val(c, i)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the length of the hex string encoding `len` bytes.
+///
+/// This is the size of the output buffer required by
+/// [`encode_to_slice()`], and can be used in const contexts.
+///
+/// # Example
+///
+/// ```
+/// const KEY_HEX_LEN: usize = hex::encoded_len(32);
+///
+/// let mut buf = [0; KEY_HEX_LEN];
+/// assert_eq!(hex::encode_to_slice([0xab; 32], &mut buf), Ok(()));
+/// ```
+#[must_use]
+pub const fn encoded_len(len: usize) -> usize {
+    len * 2
+}
+
+/// Returns the number of bytes decoded from a hex string of `len` digits.
+///
+/// This is the size of the output buffer required by
+/// [`decode_to_slice()`], and can be used in const contexts.
+///
+/// # Errors
+///
+/// Returns [`OddLength`](FromHexError::OddLength) if `len` is odd.
+///
+/// # Example
+///
+/// ```
+/// use hex::FromHexError;
+///
+/// assert_eq!(hex::decoded_len(64), Ok(32));
+/// assert_eq!(hex::decoded_len(63), Err(FromHexError::OddLength));
+/// ```
+pub const fn decoded_len(len: usize) -> Result<usize, FromHexError> {
+    if len % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+    Ok(len / 2)
+}
+
+// generates an iterator like this
+// (0, 1)
+// (2, 3)
+// (4, 5)
+// (6, 7)
+// ...
+#[inline]
+fn generate_iter(len: usize) -> impl Iterator<Item = (usize, usize)> {
+    (0..len).step_by(2).zip((0..len).skip(1).step_by(2))
+}
+
+// the inverse of `val`.
+#[inline]
+#[must_use]
+const fn byte2hex(byte: u8, table: &[u8; 16]) -> (u8, u8) {
+    let high = table[((byte & 0xf0) >> 4) as usize];
+    let low = table[(byte & 0x0f) as usize];
+
+    (high, low)
+}
+
+// checks at compile time that `M` digits encode `N` bytes, as
+// `[u8; 2 * N]` can't be written with stable const generics.
+struct EncodedLen<const N: usize, const M: usize>;
+
+impl<const N: usize, const M: usize> EncodedLen<N, M> {
+    const CHECK: () = assert!(
+        M == 2 * N,
+        "the output array has to be twice as long as the input"
+    );
+}
+
+/// Encodes a byte array into an array of `2 * N` hex digits, using lowercase
+/// characters.
+///
+/// The output length `M` is usually inferred, and is checked at compile
+/// time, so there are no errors to handle. As a `const fn`, this can also
+/// encode constants.
+///
+/// # Example
+///
+/// ```
+/// let hex: [u8; 8] = hex::encode_to_array(b"kiwi");
+/// assert_eq!(&hex, b"6b697769");
+///
+/// const HEX: [u8; 4] = hex::encode_to_array(&[0xca, 0xfe]);
+/// assert_eq!(&HEX, b"cafe");
+/// ```
+///
+/// An output array of the wrong length fails to compile:
+///
+/// ```compile_fail
+/// let hex: [u8; 7] = hex::encode_to_array(b"kiwi");
+/// ```
+#[must_use]
+pub const fn encode_to_array<const N: usize, const M: usize>(data: &[u8; N]) -> [u8; M] {
+    encode_to_array_inner(data, HEX_CHARS_LOWER)
+}
+
+/// Encodes a byte array into an array of `2 * N` hex digits, using uppercase
+/// characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_to_array()`].
+///
+/// # Example
+///
+/// ```
+/// let hex: [u8; 4] = hex::encode_upper_to_array(&[0xca, 0xfe]);
+/// assert_eq!(&hex, b"CAFE");
+/// ```
+#[must_use]
+pub const fn encode_upper_to_array<const N: usize, const M: usize>(data: &[u8; N]) -> [u8; M] {
+    encode_to_array_inner(data, HEX_CHARS_UPPER)
+}
+
+const fn encode_to_array_inner<const N: usize, const M: usize>(
+    data: &[u8; N],
+    table: &[u8; 16],
+) -> [u8; M] {
+    let () = EncodedLen::<N, M>::CHECK;
+
+    let mut out = [0; M];
+    let mut i = 0;
+    while i < N {
+        let (high, low) = byte2hex(data[i], table);
+        out[2 * i] = high;
+        out[2 * i + 1] = low;
+        i += 1;
+    }
+    out
+}
+
+/// Encodes some bytes into a mutable slice of bytes.
+///
+/// The output buffer, has to be able to hold exactly `input.len() * 2` bytes,
+/// otherwise this function will return an error.
+///
+/// # Example
+///
+/// ```
+/// # use hex::FromHexError;
+/// # fn main() -> Result<(), FromHexError> {
+/// let mut bytes = [0u8; 4 * 2];
+///
+/// hex::encode_to_slice(b"kiwi", &mut bytes)?;
+/// assert_eq!(&bytes, b"6b697769");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// If the buffer is too large, an error is returned:
+///
+/// ```
+/// use hex::FromHexError;
+/// # fn main() -> Result<(), FromHexError> {
+/// let mut bytes = [0_u8; 5 * 2];
+///
+/// assert_eq!(hex::encode_to_slice(b"kiwi", &mut bytes), Err(FromHexError::InvalidStringLength));
+///
+/// // you can do this instead:
+/// hex::encode_to_slice(b"kiwi", &mut bytes[..4 * 2])?;
+/// assert_eq!(&bytes, b"6b697769\0\0");
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_to_slice<T: AsRef<[u8]>>(input: T, output: &mut [u8]) -> Result<(), FromHexError> {
+    encode_to_slice_inner(input.as_ref(), output, HEX_CHARS_LOWER)
+}
+
+/// Encodes some bytes into a mutable slice of bytes using uppercase
+/// characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_to_slice()`].
+///
+/// # Example
+///
+/// ```
+/// # use hex::FromHexError;
+/// # fn main() -> Result<(), FromHexError> {
+/// let mut bytes = [0u8; 4 * 2];
+///
+/// hex::encode_upper_to_slice(b"\x0a\xbc\xde\xf0", &mut bytes)?;
+/// assert_eq!(&bytes, b"0ABCDEF0");
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_upper_to_slice<T: AsRef<[u8]>>(
+    input: T,
+    output: &mut [u8],
+) -> Result<(), FromHexError> {
+    encode_to_slice_inner(input.as_ref(), output, HEX_CHARS_UPPER)
+}
+
+/// Encodes some bytes into a mutable slice of bytes using the letter casing
+/// selected by `case`.
+///
+/// This works exactly like [`encode_to_slice()`] or
+/// [`encode_upper_to_slice()`], depending on `case`.
+///
+/// # Example
+///
+/// ```
+/// use hex::Case;
+/// # fn main() -> Result<(), hex::FromHexError> {
+/// let mut bytes = [0u8; 2 * 2];
+///
+/// hex::encode_to_slice_with_case([0xbe, 0xef], &mut bytes, Case::Upper)?;
+/// assert_eq!(&bytes, b"BEEF");
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_to_slice_with_case<T: AsRef<[u8]>>(
+    input: T,
+    output: &mut [u8],
+    case: Case,
+) -> Result<(), FromHexError> {
+    encode_to_slice_inner(input.as_ref(), output, case.table())
+}
+
+/// Encodes some bytes into a mutable slice of bytes, prefixed with `0x`.
+///
+/// The output buffer has to be able to hold exactly `2 + input.len() * 2`
+/// bytes, otherwise this function will return an error.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), hex::FromHexError> {
+/// let mut bytes = [0u8; 2 + 4 * 2];
+///
+/// hex::encode_prefixed_to_slice(b"kiwi", &mut bytes)?;
+/// assert_eq!(&bytes, b"0x6b697769");
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_prefixed_to_slice<T: AsRef<[u8]>>(
+    input: T,
+    output: &mut [u8],
+) -> Result<(), FromHexError> {
+    encode_prefixed_to_slice_inner(input.as_ref(), output, HEX_CHARS_LOWER)
+}
+
+/// Encodes some bytes into a mutable slice of bytes using uppercase
+/// characters, prefixed with `0x`.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_prefixed_to_slice()`].
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), hex::FromHexError> {
+/// let mut bytes = [0u8; 2 + 2 * 2];
+///
+/// hex::encode_upper_prefixed_to_slice([0xbe, 0xef], &mut bytes)?;
+/// assert_eq!(&bytes, b"0xBEEF");
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_upper_prefixed_to_slice<T: AsRef<[u8]>>(
+    input: T,
+    output: &mut [u8],
+) -> Result<(), FromHexError> {
+    encode_prefixed_to_slice_inner(input.as_ref(), output, HEX_CHARS_UPPER)
+}
+
+fn encode_prefixed_to_slice_inner(
+    input: &[u8],
+    output: &mut [u8],
+    table: &[u8; 16],
+) -> Result<(), FromHexError> {
+    if output.len() < 2 {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    let (prefix, digits) = output.split_at_mut(2);
+    encode_to_slice_inner(input, digits, table)?;
+    prefix.copy_from_slice(b"0x");
+
+    Ok(())
+}
+
+/// Encodes the bytes of several buffers into a single mutable slice of bytes.
+///
+/// The buffers are encoded one after another, as if they were a single
+/// contiguous input. This accepts anything dereferencing to `[u8]`, such as
+/// `&[u8]`, `Vec<u8>` or `std::io::IoSlice`, so scatter lists can be encoded
+/// without copying them together first.
+///
+/// The output buffer has to be able to hold exactly twice the total length of
+/// the inputs, otherwise this function will return an error.
+///
+/// # Example
+///
+/// ```
+/// use std::io::IoSlice;
+///
+/// # fn main() -> Result<(), hex::FromHexError> {
+/// let header = [0x45, 0x00];
+/// let payload = b"kiwi";
+/// let bufs = [IoSlice::new(&header), IoSlice::new(payload)];
+///
+/// let mut output = [0u8; 6 * 2];
+/// hex::encode_vectored_to_slice(&bufs, &mut output)?;
+/// assert_eq!(&output, b"45006b697769");
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_vectored_to_slice<B: Deref<Target = [u8]>>(
+    bufs: &[B],
+    output: &mut [u8],
+) -> Result<(), FromHexError> {
+    encode_vectored_to_slice_inner(bufs, output, HEX_CHARS_LOWER)
+}
+
+/// Encodes the bytes of several buffers into a single mutable slice of bytes
+/// using uppercase characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_vectored_to_slice()`].
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), hex::FromHexError> {
+/// let bufs: [&[u8]; 2] = [&[0xab], &[0xcd, 0xef]];
+///
+/// let mut output = [0u8; 3 * 2];
+/// hex::encode_upper_vectored_to_slice(&bufs, &mut output)?;
+/// assert_eq!(&output, b"ABCDEF");
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_upper_vectored_to_slice<B: Deref<Target = [u8]>>(
+    bufs: &[B],
+    output: &mut [u8],
+) -> Result<(), FromHexError> {
+    encode_vectored_to_slice_inner(bufs, output, HEX_CHARS_UPPER)
+}
+
+fn encode_vectored_to_slice_inner<B: Deref<Target = [u8]>>(
+    bufs: &[B],
+    output: &mut [u8],
+    table: &[u8; 16],
+) -> Result<(), FromHexError> {
+    let len: usize = bufs.iter().map(|buf| buf.len()).sum();
+    if len * 2 != output.len() {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    let mut output = output;
+    for buf in bufs {
+        let (head, tail) = output.split_at_mut(buf.len() * 2);
+        encode_raw(buf, head, table);
+        output = tail;
+    }
+
+    Ok(())
+}
+
+/// Encodes some bytes into a slice of possibly uninitialized bytes.
+///
+/// This works like [`encode_to_slice()`], but doesn't require the output to be
+/// initialized (e.g. zero-filled) up front. On success the now initialized
+/// output is returned as string slice.
+///
+/// The output buffer has to be able to hold exactly `input.len() * 2` bytes,
+/// otherwise this function will return an error.
+///
+/// # Example
+///
+/// ```
+/// use core::mem::MaybeUninit;
+///
+/// # fn main() -> Result<(), hex::FromHexError> {
+/// let mut buf = [MaybeUninit::uninit(); 4 * 2];
+///
+/// assert_eq!(hex::encode_to_uninit_slice(b"kiwi", &mut buf)?, "6b697769");
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_to_uninit_slice<T: AsRef<[u8]>>(
+    input: T,
+    output: &mut [MaybeUninit<u8>],
+) -> Result<&str, FromHexError> {
+    encode_to_uninit_slice_inner(input.as_ref(), output, HEX_CHARS_LOWER)
+}
+
+/// Encodes some bytes into a slice of possibly uninitialized bytes using
+/// uppercase characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_to_uninit_slice()`].
+///
+/// # Example
+///
+/// ```
+/// use core::mem::MaybeUninit;
+///
+/// # fn main() -> Result<(), hex::FromHexError> {
+/// let mut buf = [MaybeUninit::uninit(); 2 * 2];
+///
+/// assert_eq!(hex::encode_upper_to_uninit_slice([0xbe, 0xef], &mut buf)?, "BEEF");
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_upper_to_uninit_slice<T: AsRef<[u8]>>(
+    input: T,
+    output: &mut [MaybeUninit<u8>],
+) -> Result<&str, FromHexError> {
+    encode_to_uninit_slice_inner(input.as_ref(), output, HEX_CHARS_UPPER)
+}
+
+fn encode_to_uninit_slice_inner<'a>(
+    input: &[u8],
+    output: &'a mut [MaybeUninit<u8>],
+    table: &[u8; 16],
+) -> Result<&'a str, FromHexError> {
+    if input.len() * 2 != output.len() {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    for (byte, pair) in input.iter().zip(output.chunks_exact_mut(2)) {
+        let (high, low) = byte2hex(*byte, table);
+        pair[0] = MaybeUninit::new(high);
+        pair[1] = MaybeUninit::new(low);
+    }
+
+    // SAFETY: every element of `output` has been initialized above, and
+    // `MaybeUninit<u8>` is guaranteed to have the same layout as `u8`.
+    let output = unsafe { &*(output as *const [MaybeUninit<u8>] as *const [u8]) };
+
+    // SAFETY: the tables only contain ascii characters.
+    Ok(unsafe { core::str::from_utf8_unchecked(output) })
+}
+
+fn encode_to_slice_inner(
+    input: &[u8],
+    output: &mut [u8],
+    table: &[u8; 16],
+) -> Result<(), FromHexError> {
+    if input.len() * 2 != output.len() {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    encode_raw(input, output, table);
+
+    Ok(())
+}
+
+// encodes `input` into the first `input.len() * 2` bytes of `output`, which
+// the caller has to make sure are available.
+#[inline]
+fn encode_raw(input: &[u8], output: &mut [u8], table: &[u8; 16]) {
+    for (byte, (i, j)) in input.iter().zip(generate_iter(input.len() * 2)) {
+        let (high, low) = byte2hex(*byte, table);
+        output[i] = high;
+        output[j] = low;
+    }
+}
+
+/// Writes the hex representation of `data` into a [`fmt::Write`] sink using
+/// lowercase characters.
+///
+/// The data is encoded in small chunks on the stack, so this neither
+/// allocates nor collects single `char`s. This makes it usable with
+/// formatters, `no_std` string types and custom display sinks alike.
+///
+/// # Example
+///
+/// ```
+/// use core::fmt::Write;
+///
+/// struct Id([u8; 4]);
+///
+/// impl core::fmt::Display for Id {
+///     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+///         f.write_str("id:")?;
+///         hex::encode_to_fmt(self.0, f)
+///     }
+/// }
+///
+/// assert_eq!(Id(*b"kiwi").to_string(), "id:6b697769");
+/// ```
+pub fn encode_to_fmt<T, W>(data: T, w: &mut W) -> fmt::Result
+where
+    T: AsRef<[u8]>,
+    W: fmt::Write + ?Sized,
+{
+    encode_to_fmt_inner(data.as_ref(), w, HEX_CHARS_LOWER)
+}
+
+/// Writes the hex representation of `data` into a [`fmt::Write`] sink using
+/// uppercase characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_to_fmt()`].
+///
+/// # Example
+///
+/// ```
+/// let mut s = String::new();
+/// hex::encode_upper_to_fmt([0xbe, 0xef], &mut s).unwrap();
+/// assert_eq!(s, "BEEF");
+/// ```
+pub fn encode_upper_to_fmt<T, W>(data: T, w: &mut W) -> fmt::Result
+where
+    T: AsRef<[u8]>,
+    W: fmt::Write + ?Sized,
+{
+    encode_to_fmt_inner(data.as_ref(), w, HEX_CHARS_UPPER)
+}
+
+fn encode_to_fmt_inner<W: fmt::Write + ?Sized>(
+    data: &[u8],
+    w: &mut W,
+    table: &[u8; 16],
+) -> fmt::Result {
+    let mut buf = [0_u8; 128];
+
+    for chunk in data.chunks(buf.len() / 2) {
+        let out = &mut buf[..chunk.len() * 2];
+        encode_raw(chunk, out, table);
+        // the tables only contain ascii characters
+        w.write_str(core::str::from_utf8(out).map_err(|_| fmt::Error)?)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::string::ToString;
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_gen_iter() {
+        let result = vec![(0, 1), (2, 3)];
+
+        assert_eq!(generate_iter(5).collect::<Vec<_>>(), result);
+    }
+
+    #[test]
+    fn test_encode_to_slice() {
+        let mut output_1 = [0; 4 * 2];
+        encode_to_slice(b"kiwi", &mut output_1).unwrap();
+        assert_eq!(&output_1, b"6b697769");
+
+        let mut output_2 = [0; 5 * 2];
+        encode_to_slice(b"kiwis", &mut output_2).unwrap();
+        assert_eq!(&output_2, b"6b69776973");
+
+        let mut output_3 = [0; 100];
+
+        assert_eq!(
+            encode_to_slice(b"kiwis", &mut output_3),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn test_encode_upper_to_slice() {
+        let mut output_1 = [0; 4 * 2];
+        encode_upper_to_slice(b"kiwi", &mut output_1).unwrap();
+        assert_eq!(&output_1, b"6B697769");
+
+        let mut output_2 = [0; 3 * 2];
+        encode_upper_to_slice([0xde, 0xad, 0xff], &mut output_2).unwrap();
+        assert_eq!(&output_2, b"DEADFF");
+
+        let mut output_3 = [0; 7];
+
+        assert_eq!(
+            encode_upper_to_slice(b"kiwi", &mut output_3),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn test_encode_to_slice_with_case() {
+        let mut output = [0; 3 * 2];
+
+        encode_to_slice_with_case([0xab, 0x01, 0xef], &mut output, Case::Lower).unwrap();
+        assert_eq!(&output, b"ab01ef");
+
+        encode_to_slice_with_case([0xab, 0x01, 0xef], &mut output, Case::Upper).unwrap();
+        assert_eq!(&output, b"AB01EF");
+
+        assert_eq!(
+            encode_to_slice_with_case([0xab], &mut output, Case::Upper),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn test_encode_to_fmt() {
+        struct Sink {
+            buf: [u8; 512],
+            len: usize,
+        }
+
+        impl fmt::Write for Sink {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                self.buf
+                    .get_mut(self.len..end)
+                    .ok_or(fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let mut sink = Sink {
+            buf: [0; 512],
+            len: 0,
+        };
+        encode_to_fmt(b"kiwi", &mut sink).unwrap();
+        encode_upper_to_fmt([0xab; 100], &mut sink).unwrap();
+
+        assert_eq!(&sink.buf[..8], b"6b697769");
+        assert_eq!(sink.len, 8 + 200);
+        assert!(sink.buf[8..sink.len].chunks(2).all(|pair| pair == b"AB"));
+
+        assert_eq!(encode_to_fmt([0; 300], &mut sink), Err(fmt::Error));
+    }
+
+    #[test]
+    fn test_encode_prefixed_to_slice() {
+        let mut output_1 = [0; 2 + 4 * 2];
+        encode_prefixed_to_slice(b"kiwi", &mut output_1).unwrap();
+        assert_eq!(&output_1, b"0x6b697769");
+
+        let mut output_2 = [0; 2];
+        encode_upper_prefixed_to_slice([], &mut output_2).unwrap();
+        assert_eq!(&output_2, b"0x");
+
+        let mut output_3 = [0; 4 * 2];
+        assert_eq!(
+            encode_prefixed_to_slice(b"kiwi", &mut output_3),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            encode_prefixed_to_slice([], &mut output_3[..1]),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn test_encode_vectored_to_slice() {
+        let bufs: [&[u8]; 3] = [b"ki", b"", b"wi"];
+
+        let mut output = [0; 4 * 2];
+        encode_vectored_to_slice(&bufs, &mut output).unwrap();
+        assert_eq!(&output, b"6b697769");
+
+        let bufs: [&[u8]; 2] = [&[0xab, 0xcd], &[0xef, 0x01]];
+        encode_upper_vectored_to_slice(&bufs, &mut output).unwrap();
+        assert_eq!(&output, b"ABCDEF01");
+
+        assert_eq!(
+            encode_vectored_to_slice(&bufs[..1], &mut output),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn test_encode_to_uninit_slice() {
+        let mut output_1 = [MaybeUninit::uninit(); 4 * 2];
+        assert_eq!(
+            encode_to_uninit_slice(b"kiwi", &mut output_1),
+            Ok("6b697769")
+        );
+
+        let mut output_2 = [MaybeUninit::uninit(); 3 * 2];
+        assert_eq!(
+            encode_upper_to_uninit_slice([0x0a, 0xbc, 0xde], &mut output_2),
+            Ok("0ABCDE")
+        );
+
+        assert_eq!(
+            encode_to_uninit_slice(b"kiwi", &mut output_2),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(encode_to_uninit_slice([], &mut []), Ok(""));
+    }
+
+    #[test]
+    fn test_decode_to_slice() {
+        let mut output_1 = [0; 4];
+        decode_to_slice(b"6b697769", &mut output_1).unwrap();
+        assert_eq!(&output_1, b"kiwi");
+
+        let mut output_2 = [0; 5];
+        decode_to_slice(b"6b69776973", &mut output_2).unwrap();
+        assert_eq!(&output_2, b"kiwis");
+
+        let mut output_3 = [0; 4];
+
+        assert_eq!(
+            decode_to_slice(b"6", &mut output_3),
+            Err(FromHexError::OddLength)
+        );
+    }
+
+    #[test]
+    fn test_from_hex_iter_array() {
+        assert_eq!(<[u8; 0]>::from_hex_iter("".chars()), Ok([]));
+        assert_eq!(<[u8; 2]>::from_hex_iter("0aFf".bytes()), Ok([0x0a, 0xff]));
+        assert_eq!(
+            <[u8; 2]>::from_hex_iter("0aF".chars()),
+            Err(FromHexError::OddLength)
+        );
+        assert_eq!(
+            <[u8; 2]>::from_hex_iter("0a".chars()),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            <[u8; 1]>::from_hex_iter("0a0b".chars()),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            <[u8; 2]>::from_hex_iter("0a\u{e9}b".chars()),
+            Err(FromHexError::InvalidHexCharacter {
+                c: '\u{e9}',
+                index: 2
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_hex_iter_vec() {
+        assert_eq!(Vec::from_hex_iter("".chars()), Ok(vec![]));
+        assert_eq!(
+            Vec::from_hex_iter(vec![b'0', b'a', b'F', b'f']),
+            Ok(vec![0x0a, 0xff])
+        );
+        assert_eq!(
+            Vec::from_hex_iter("0aF".chars()),
+            Err(FromHexError::OddLength)
+        );
+        assert_eq!(
+            Vec::from_hex_iter("0a g".chars()),
+            Err(FromHexError::InvalidHexCharacter { c: ' ', index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_decode_iter() {
+        let mut bytes = DecodeIter::new(b"0aFf");
+        assert_eq!(bytes.size_hint(), (1, Some(2)));
+        assert_eq!(bytes.next(), Some(Ok(0x0a)));
+        assert_eq!(bytes.next(), Some(Ok(0xff)));
+        assert_eq!(bytes.size_hint(), (0, Some(0)));
+        assert_eq!(bytes.next(), None);
+
+        let mut bytes = DecodeIter::new("0aF");
+        assert_eq!(bytes.size_hint(), (1, Some(2)));
+        assert_eq!(bytes.next(), Some(Ok(0x0a)));
+        assert_eq!(bytes.next(), Some(Err(FromHexError::OddLength)));
+        assert_eq!(bytes.next(), None);
+
+        let mut bytes = DecodeIter::new("x0aF");
+        assert_eq!(
+            bytes.next(),
+            Some(Err(FromHexError::InvalidHexCharacter { c: 'x', index: 0 }))
+        );
+        assert_eq!(bytes.next(), None);
+
+        assert_eq!(DecodeIter::new("").next(), None);
+    }
+
+    #[test]
+    fn test_decode_to_array() {
+        assert_eq!(decode_to_array::<0, _>(""), Ok([]));
+        assert_eq!(decode_to_array::<3, _>("0aFf10"), Ok([0x0a, 0xff, 0x10]));
+        assert_eq!(
+            decode_to_array::<2, _>("0aFf10"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(decode_to_array::<2, _>("0aF"), Err(FromHexError::OddLength));
+    }
+
+    #[test]
+    fn test_decode_to_slice_partial() {
+        let mut output = [0xff; 4];
+        assert_eq!(decode_to_slice_partial("", &mut output), Ok(0));
+        assert_eq!(decode_to_slice_partial("0102", &mut output), Ok(2));
+        assert_eq!(output, [0x01, 0x02, 0xff, 0xff]);
+        assert_eq!(decode_to_slice_partial("0a0b0c0d", &mut output), Ok(4));
+        assert_eq!(output, [0x0a, 0x0b, 0x0c, 0x0d]);
+
+        assert_eq!(
+            decode_to_slice_partial("0a0b0c0d0e", &mut output),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_to_slice_partial("0a0", &mut output),
+            Err(FromHexError::OddLength)
+        );
+        assert_eq!(
+            decode_to_slice_partial("0g", &mut output),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_decode_to_slice_unchecked() {
+        for c in 0..=255_u8 {
+            if let Some(value) = hex_digit_to_value(c) {
+                assert_eq!(val_unchecked(c), value);
+            }
+        }
+
+        let mut output = [0; 4];
+        decode_to_slice_unchecked("01aBCdef", &mut output).unwrap();
+        assert_eq!(output, [0x01, 0xab, 0xcd, 0xef]);
+        decode_to_slice_unchecked(b"\xff\xff\x00\x00    ", &mut output).unwrap();
+
+        assert_eq!(
+            decode_to_slice_unchecked("010", &mut output),
+            Err(FromHexError::OddLength)
+        );
+        assert_eq!(
+            decode_to_slice_unchecked("0102", &mut output),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn test_decode_to_uninit_slice() {
+        let mut output = [MaybeUninit::uninit(); 3];
+        let decoded = decode_to_uninit_slice("0aFf10", &mut output).unwrap();
+        assert_eq!(decoded, [0x0a, 0xff, 0x10]);
+        decoded[0] = 0;
+
+        assert_eq!(
+            decode_to_uninit_slice("0a0b", &mut output),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_to_uninit_slice("0a0b0", &mut output),
+            Err(FromHexError::OddLength)
+        );
+        assert_eq!(
+            decode_to_uninit_slice("0a0b0x", &mut output),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 5 })
+        );
+    }
+
+    #[test]
+    fn test_decode_in_slice() {
+        let mut buf = [];
+        assert_eq!(decode_in_slice(&mut buf), Ok(&mut [][..]));
+
+        let mut buf = *b"0aFf10";
+        let decoded = decode_in_slice(&mut buf).unwrap();
+        assert_eq!(decoded, [0x0a, 0xff, 0x10]);
+        decoded[0] = 0;
+        assert_eq!(buf, *b"\x00\xff\x10f10");
+
+        let mut buf = *b"0aFf1";
+        assert_eq!(decode_in_slice(&mut buf), Err(FromHexError::OddLength));
+        let mut buf = *b"0aFf1x";
+        assert_eq!(
+            decode_in_slice(&mut buf),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 5 })
+        );
+        assert_eq!(buf, *b"0aFf1x");
+    }
+
+    #[test]
+    fn test_decode_in_slice_zeroing() {
+        let mut buf = *b"0aFf10";
+        let decoded = decode_in_slice_zeroing(&mut buf).unwrap();
+        assert_eq!(decoded, [0x0a, 0xff, 0x10]);
+        assert_eq!(buf, [0x0a, 0xff, 0x10, 0, 0, 0]);
+
+        let mut buf = *b"0aFf1x";
+        assert_eq!(
+            decode_in_slice_zeroing(&mut buf),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 5 })
+        );
+        assert_eq!(buf, *b"0aFf1x");
+    }
+
+    #[test]
+    fn test_decode_nibbles_to_slice() {
+        let mut output = [0; 3];
+        decode_nibbles_to_slice(b"abcde", &mut output).unwrap();
+        assert_eq!(output, [0xab, 0xcd, 0xe0]);
+
+        decode_nibbles_to_slice(b"abcdef", &mut output).unwrap();
+        assert_eq!(output, [0xab, 0xcd, 0xef]);
+
+        assert_eq!(
+            decode_nibbles_to_slice(b"abcd", &mut output),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn test_decode_swapped_to_slice() {
+        let mut output = [0; 2];
+        decode_swapped_to_slice(b"81f9", &mut output).unwrap();
+        assert_eq!(output, [0x18, 0x9f]);
 
-    if data.len() % 2 != 0 {
-        return Err(FromHexError::OddLength);
+        assert_eq!(
+            decode_swapped_to_slice(b"81g9", &mut output),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 2 })
+        );
     }
-    if data.len() / 2 != out.len() {
-        return Err(FromHexError::InvalidStringLength);
+
+    #[test]
+    fn test_decode_reverse_to_slice() {
+        let mut output = [0; 3];
+        decode_reverse_to_slice(b"010203", &mut output).unwrap();
+        assert_eq!(output, [3, 2, 1]);
+
+        assert_eq!(
+            decode_reverse_to_slice(b"0102", &mut output),
+            Err(FromHexError::InvalidStringLength)
+        );
     }
 
-    for (i, byte) in out.iter_mut().enumerate() {
-        *byte = val(data[2 * i], 2 * i)? << 4 | val(data[2 * i + 1], 2 * i + 1)?;
+    #[test]
+    fn test_detect_case() {
+        assert_eq!(detect_case(""), Casing::NoLetters);
+        assert_eq!(detect_case("0x00:11"), Casing::NoLetters);
+        assert_eq!(detect_case("0xab:cd"), Casing::Lower);
+        assert_eq!(detect_case("0XAB:CD"), Casing::Upper);
+        assert_eq!(detect_case("GHab"), Casing::Lower);
+        assert_eq!(detect_case("aB"), Casing::Mixed);
+        assert_eq!(detect_case("AAAAb"), Casing::Mixed);
+
+        assert_eq!(Casing::NoLetters.case(), Some(Case::Lower));
+        assert_eq!(Casing::Lower.case(), Some(Case::Lower));
     }
 
-    Ok(())
-}
+    #[test]
+    fn test_hex_digits() {
+        for value in 0..16 {
+            for case in [Case::Lower, Case::Upper] {
+                let digit = value_to_hex_digit(value, case);
+                assert_eq!(hex_digit_to_value(digit), Some(value));
+            }
+        }
 
-// generates an iterator like this
-// (0, 1)
-// (2, 3)
-// (4, 5)
-// (6, 7)
-// ...
-#[inline]
-fn generate_iter(len: usize) -> impl Iterator<Item = (usize, usize)> {
-    (0..len).step_by(2).zip((0..len).skip(1).step_by(2))
-}
+        for c in 0..=255 {
+            assert_eq!(hex_digit_to_value(c).is_some(), c.is_ascii_hexdigit());
+        }
+    }
 
-// the inverse of `val`.
-#[inline]
-#[must_use]
-const fn byte2hex(byte: u8, table: &[u8; 16]) -> (u8, u8) {
-    let high = table[((byte & 0xf0) >> 4) as usize];
-    let low = table[(byte & 0x0f) as usize];
+    #[test]
+    #[should_panic(expected = "value of a hex digit must be less than 16")]
+    fn test_value_to_hex_digit_overflow() {
+        let _ = value_to_hex_digit(16, Case::Lower);
+    }
 
-    (high, low)
-}
+    #[test]
+    fn test_encoded_decoded_len() {
+        assert_eq!(encoded_len(0), 0);
+        assert_eq!(encoded_len(3), 6);
+        assert_eq!(decoded_len(0), Ok(0));
+        assert_eq!(decoded_len(6), Ok(3));
+        assert_eq!(decoded_len(7), Err(FromHexError::OddLength));
 
-/// Encodes some bytes into a mutable slice of bytes.
-///
-/// The output buffer, has to be able to hold exactly `input.len() * 2` bytes,
-/// otherwise this function will return an error.
-///
-/// # Example
-///
-/// ```
-/// # use hex::FromHexError;
-/// # fn main() -> Result<(), FromHexError> {
-/// let mut bytes = [0u8; 4 * 2];
-///
-/// hex::encode_to_slice(b"kiwi", &mut bytes)?;
-/// assert_eq!(&bytes, b"6b697769");
-/// # Ok(())
-/// # }
-/// ```
-///
-/// If the buffer is too large, an error is returned:
-///
-/// ```
-/// use hex::FromHexError;
-/// # fn main() -> Result<(), FromHexError> {
-/// let mut bytes = [0_u8; 5 * 2];
-///
-/// assert_eq!(hex::encode_to_slice(b"kiwi", &mut bytes), Err(FromHexError::InvalidStringLength));
-///
-/// // you can do this instead:
-/// hex::encode_to_slice(b"kiwi", &mut bytes[..4 * 2])?;
-/// assert_eq!(&bytes, b"6b697769\0\0");
-/// # Ok(())
-/// # }
-/// ```
-pub fn encode_to_slice<T: AsRef<[u8]>>(input: T, output: &mut [u8]) -> Result<(), FromHexError> {
-    if input.as_ref().len() * 2 != output.len() {
-        return Err(FromHexError::InvalidStringLength);
+        for len in 0..8 {
+            assert_eq!(decoded_len(encoded_len(len)), Ok(len));
+        }
     }
 
-    for (byte, (i, j)) in input
-        .as_ref()
-        .iter()
-        .zip(generate_iter(input.as_ref().len() * 2))
-    {
-        let (high, low) = byte2hex(*byte, HEX_CHARS_LOWER);
-        output[i] = high;
-        output[j] = low;
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(""), Ok(()));
+        assert_eq!(validate("0123456789abcdefABCDEF"), Ok(()));
+        assert_eq!(validate("0g1"), Err(FromHexError::OddLength));
+        assert_eq!(
+            validate("0 12"),
+            Err(FromHexError::InvalidHexCharacter { c: ' ', index: 1 })
+        );
     }
 
-    Ok(())
-}
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode() {
+        assert_eq!(encode("foobar"), "666f6f626172");
+    }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    #[test]
     #[cfg(feature = "alloc")]
-    use alloc::string::ToString;
+    fn test_encode_with_case() {
+        assert_eq!(encode_with_case("foobar", Case::Lower), encode("foobar"));
+        assert_eq!(
+            encode_with_case("foobar", Case::Upper),
+            encode_upper("foobar")
+        );
+    }
+
+    #[test]
     #[cfg(feature = "alloc")]
-    use alloc::vec;
-    use pretty_assertions::assert_eq;
+    fn test_encode_append() {
+        let mut buf = String::from("foo:");
+        encode_append(&mut buf, "bar");
+        assert_eq!(buf, "foo:626172");
+
+        encode_upper_append(&mut buf, [0xab, 0xcd]);
+        assert_eq!(buf, "foo:626172ABCD");
+
+        encode_append(&mut buf, []);
+        assert_eq!(buf, "foo:626172ABCD");
+    }
 
     #[test]
     #[cfg(feature = "alloc")]
-    fn test_gen_iter() {
-        let result = vec![(0, 1), (2, 3)];
+    fn test_encode_prefixed() {
+        assert_eq!(encode_prefixed("foobar"), "0x666f6f626172");
+        assert_eq!(encode_upper_prefixed([0xab, 0xcd]), "0xABCD");
+    }
 
-        assert_eq!(generate_iter(5).collect::<Vec<_>>(), result);
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_many() {
+        let parts = vec![b"foo".to_vec(), vec![], b"bar".to_vec()];
+
+        let encoded = encode_many(&parts);
+        assert_eq!(encoded, "666f6f626172");
+        assert_eq!(encoded.capacity(), 12);
+
+        let encoded = encode_many_with_separator(&parts, ':');
+        assert_eq!(encoded, "666f6f::626172");
+        assert_eq!(encoded.capacity(), 14);
+
+        assert_eq!(encode_many(iter::empty::<&[u8]>()), "");
+        assert_eq!(encode_many_with_separator([[0xab]], ':'), "ab");
     }
 
     #[test]
-    fn test_encode_to_slice() {
-        let mut output_1 = [0; 4 * 2];
-        encode_to_slice(b"kiwi", &mut output_1).unwrap();
-        assert_eq!(&output_1, b"6b697769");
+    #[cfg(feature = "alloc")]
+    fn test_encode_in_vec() {
+        let mut buf = b"foobar".to_vec();
+        encode_in_vec(&mut buf);
+        assert_eq!(buf, b"666f6f626172");
 
-        let mut output_2 = [0; 5 * 2];
-        encode_to_slice(b"kiwis", &mut output_2).unwrap();
-        assert_eq!(&output_2, b"6b69776973");
+        let mut buf = vec![0x0a, 0xbc];
+        encode_upper_in_vec(&mut buf);
+        assert_eq!(buf, b"0ABC");
 
-        let mut output_3 = [0; 100];
+        let mut buf = vec![];
+        encode_in_vec(&mut buf);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_iter() {
+        let parts: [&[u8]; 3] = [b"foo", b"", b"bar"];
+        let bytes = parts.iter().flat_map(|part| part.iter().copied());
+        assert_eq!(encode_iter(bytes), "666f6f626172");
+
+        assert_eq!(encode_upper_iter([0xab, 0xcd]), "ABCD");
+        assert_eq!(encode_iter(iter::empty()), "");
+    }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_decode_reverse() {
+        assert_eq!(encode_reverse("raboof"), "666f6f626172");
+        assert_eq!(encode_upper_reverse([0xab, 0xcd]), "CDAB");
+        assert_eq!(decode_reverse("666f6f626172"), Ok(b"raboof".to_vec()));
         assert_eq!(
-            encode_to_slice(b"kiwis", &mut output_3),
+            decode_reverse("66zz"),
+            Err(FromHexError::InvalidHexCharacter { c: 'z', index: 2 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_decode_swapped() {
+        assert_eq!(encode_swapped([0x10, 0x32, 0xab]), "0123ba");
+        assert_eq!(encode_upper_swapped([0x10, 0x32, 0xab]), "0123BA");
+        assert_eq!(decode_swapped("0123ba"), Ok(vec![0x10, 0x32, 0xab]));
+        assert_eq!(decode_swapped("012"), Err(FromHexError::OddLength));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_decode_nibbles() {
+        assert_eq!(encode_nibbles([0x12, 0x34], 0), Ok(String::new()));
+        assert_eq!(encode_nibbles([0x12, 0x34], 1), Ok("1".to_string()));
+        assert_eq!(encode_nibbles([0x12, 0x34], 3), Ok("123".to_string()));
+        assert_eq!(
+            encode_nibbles([0x12, 0x34], 5),
             Err(FromHexError::InvalidStringLength)
         );
+
+        assert_eq!(decode_nibbles(""), Ok((vec![], 0)));
+        assert_eq!(decode_nibbles("f"), Ok((vec![0xf0], 1)));
+        assert_eq!(decode_nibbles("12345"), Ok((vec![0x12, 0x34, 0x50], 5)));
+        assert_eq!(
+            decode_nibbles("1234g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 4 })
+        );
+
+        let (bytes, nibbles) = decode_nibbles("abcde").unwrap();
+        assert_eq!(encode_nibbles(bytes, nibbles).unwrap(), "abcde");
     }
 
     #[test]
-    fn test_decode_to_slice() {
-        let mut output_1 = [0; 4];
-        decode_to_slice(b"6b697769", &mut output_1).unwrap();
-        assert_eq!(&output_1, b"kiwi");
+    #[cfg(feature = "alloc")]
+    fn test_decode() {
+        assert_eq!(
+            decode("666f6f626172"),
+            Ok(String::from("foobar").into_bytes())
+        );
+    }
 
-        let mut output_2 = [0; 5];
-        decode_to_slice(b"6b69776973", &mut output_2).unwrap();
-        assert_eq!(&output_2, b"kiwis");
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_capped() {
+        assert_eq!(decode_capped("", 0), Ok(vec![]));
+        assert_eq!(decode_capped("0a0b", 2), Ok(vec![0x0a, 0x0b]));
+        assert_eq!(decode_capped("0a0b", 3), Ok(vec![0x0a, 0x0b]));
+        assert_eq!(decode_capped("0a0", 1), Err(FromHexError::OddLength));
+        assert_eq!(
+            decode_capped("0a0b", 1),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_capped("0a0", 0),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
 
-        let mut output_3 = [0; 4];
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_append() {
+        let mut buf = vec![];
+        decode_append(&mut buf, "").unwrap();
+        assert_eq!(buf, []);
+        decode_append(&mut buf, "0102").unwrap();
+        decode_append(&mut buf, "FfeE").unwrap();
+        assert_eq!(buf, [0x01, 0x02, 0xff, 0xee]);
 
         assert_eq!(
-            decode_to_slice(b"6", &mut output_3),
-            Err(FromHexError::OddLength)
+            decode_append(&mut buf, "00zz"),
+            Err(FromHexError::InvalidHexCharacter { c: 'z', index: 2 })
         );
+        assert_eq!(buf, [0x01, 0x02, 0xff, 0xee]);
     }
 
     #[test]
     #[cfg(feature = "alloc")]
-    fn test_encode() {
-        assert_eq!(encode("foobar"), "666f6f626172");
+    fn test_decode_vec_in_place() {
+        let mut buf = vec![];
+        decode_vec_in_place(&mut buf).unwrap();
+        assert_eq!(buf, []);
+
+        let mut buf = b"0123456789abcdefABCDEF".to_vec();
+        decode_vec_in_place(&mut buf).unwrap();
+        assert_eq!(
+            buf,
+            [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xab, 0xcd, 0xef]
+        );
+
+        let mut buf = b"0102g3".to_vec();
+        assert_eq!(
+            decode_vec_in_place(&mut buf),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 4 })
+        );
+        assert_eq!(buf, b"0102g3");
+
+        let mut buf = b"012".to_vec();
+        assert_eq!(decode_vec_in_place(&mut buf), Err(FromHexError::OddLength));
+        assert_eq!(buf, b"012");
+
+        let mut buf = vec![0x5a; 100];
+        encode_in_vec(&mut buf);
+        decode_vec_in_place(&mut buf).unwrap();
+        assert_eq!(buf, [0x5a; 100]);
+    }
+
+    #[test]
+    fn test_encode_to_array() {
+        let hex: [u8; 0] = encode_to_array(&[]);
+        assert_eq!(hex, []);
+
+        let hex: [u8; 12] = encode_to_array(b"foobar");
+        assert_eq!(&hex, b"666f6f626172");
+
+        let hex: [u8; 6] = encode_upper_to_array(&[0x00, 0xab, 0xff]);
+        assert_eq!(&hex, b"00ABFF");
+
+        let bytes = [0x5a; 64];
+        let hex: [u8; 128] = encode_to_array(&bytes);
+        assert_eq!(decode_to_array(hex), Ok(bytes));
     }
 
     #[test]
     #[cfg(feature = "alloc")]
-    fn test_decode() {
+    fn test_from_hex_vec_deque() {
+        let mut bytes = VecDeque::from_hex("6b697769").unwrap();
+        assert_eq!(bytes, b"kiwi");
+        bytes.pop_front();
+        bytes.push_back(b'!');
+        assert_eq!(bytes, b"iwi!");
+        assert_eq!(VecDeque::from_hex("6b6"), Err(FromHexError::OddLength));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_utf8() {
+        assert_eq!(decode_utf8(""), Ok(String::new()));
+        assert_eq!(decode_utf8("6b69c3a9"), Ok("ki\u{e9}".to_string()));
         assert_eq!(
-            decode("666f6f626172"),
-            Ok(String::from("foobar").into_bytes())
+            decode_utf8("c3"),
+            Err(FromHexError::InvalidUtf8 { valid_up_to: 0 })
+        );
+        assert_eq!(decode_utf8("6b6"), Err(FromHexError::OddLength));
+        assert_eq!(
+            String::from_hex("6b69c3a9ff"),
+            Err(FromHexError::InvalidUtf8 { valid_up_to: 4 })
         );
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_to_boxed_slice() {
+        assert_eq!(decode_to_boxed_slice(""), Ok(Box::from([])));
+        assert_eq!(decode_to_boxed_slice("666f6f"), Ok(Box::from(*b"foo")));
+        assert_eq!(decode_to_boxed_slice("666f6"), Err(FromHexError::OddLength));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_hex_cow() {
+        let cow = Cow::<[u8]>::from_hex("666f6f").unwrap();
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!(cow, &b"foo"[..]);
+        assert_eq!(Cow::<[u8]>::from_hex("666f6"), Err(FromHexError::OddLength));
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     pub fn test_from_hex_okay_str() {
@@ -506,4 +3153,108 @@ mod test {
             "666F6F626172".to_string(),
         );
     }
+
+    #[test]
+    fn test_hex_byte_iter() {
+        let mut iter = HexByteIter::with_case(&[0x0a, 0xbc], Case::Upper);
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(b'0'));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(b'A'));
+        assert_eq!(iter.next(), Some(b'B'));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.next(), Some(b'C'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+
+        assert!(HexByteIter::new(b"kiwi").eq(b"6b697769".iter().copied()));
+    }
+
+    #[test]
+    fn test_hex_byte_iter_double_ended() {
+        let mut iter = HexByteIter::new(&[0x12, 0x34, 0x56]);
+        assert_eq!(iter.next_back(), Some(b'6'));
+        assert_eq!(iter.next(), Some(b'1'));
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next_back(), Some(b'5'));
+        assert_eq!(iter.next_back(), Some(b'4'));
+        assert_eq!(iter.next(), Some(b'2'));
+        assert_eq!(iter.next(), Some(b'3'));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = HexByteIter::new(&[0xab]);
+        assert_eq!(iter.next(), Some(b'a'));
+        assert_eq!(iter.next_back(), Some(b'b'));
+        assert_eq!(iter.next_back(), None);
+
+        assert!(HexByteIter::new(b"kiwi")
+            .rev()
+            .eq(b"6b697769".iter().rev().copied()));
+    }
+
+    #[test]
+    fn test_bytes_to_hex_chars() {
+        let chars = BytesToHexChars::with_case(&[0xde, 0xad], Case::Upper);
+        assert!(chars.clone().eq("DEAD".chars()));
+        assert!(chars.rev().eq("DAED".chars()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_to_hex_to() {
+        struct Digest;
+
+        impl ToHex for Digest {
+            fn encode_hex<T: iter::FromIterator<char>>(&self) -> T {
+                [0xfa, 0xce].encode_hex()
+            }
+
+            fn encode_hex_upper<T: iter::FromIterator<char>>(&self) -> T {
+                [0xfa, 0xce].encode_hex_upper()
+            }
+        }
+
+        let mut s = String::new();
+        "foo".encode_hex_to(&mut s).unwrap();
+        "bar".encode_hex_upper_to(&mut s).unwrap();
+        assert_eq!(s, "666f6f626172");
+
+        Digest.encode_hex_to(&mut s).unwrap();
+        Digest.encode_hex_upper_to(&mut s).unwrap();
+        assert_eq!(s, "666f6f626172faceFACE");
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc"))]
+    fn test_to_hex_to_default() {
+        struct Digest;
+
+        impl ToHex for Digest {
+            fn encode_hex<T: iter::FromIterator<char>>(&self) -> T {
+                [0xfa, 0xce].encode_hex()
+            }
+
+            fn encode_hex_upper<T: iter::FromIterator<char>>(&self) -> T {
+                [0xfa, 0xce].encode_hex_upper()
+            }
+        }
+
+        struct Sink(usize);
+
+        impl fmt::Write for Sink {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+
+        let mut sink = Sink(0);
+        [0xfa, 0xce].encode_hex_to(&mut sink).unwrap();
+        assert_eq!(sink.0, 4);
+        assert_eq!(Digest.encode_hex_to(&mut sink), Err(fmt::Error));
+        assert_eq!(Digest.encode_hex_upper_to(&mut sink), Err(fmt::Error));
+        assert_eq!(sink.0, 4);
+    }
 }
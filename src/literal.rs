@@ -0,0 +1,562 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Formatting bytes as source code literals, and parsing them back.
+//!
+//! The formatters in this module render binary data in a shape that can be
+//! pasted directly into source code, e.g. to embed a firmware blob or a test
+//! fixture. [`parse`] reads the hex number literals of assembly languages
+//! and Verilog.
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+
+use core::fmt;
+
+use crate::{byte2hex, Case};
+#[cfg(feature = "alloc")]
+use crate::{val, FromHexError};
+
+/// A formatter for C array initializers.
+///
+/// Every byte is written as a `0x`-prefixed literal, and the literals are
+/// separated by commas and wrapped into lines of a fixed number of values.
+/// Optionally, the values are wrapped into a complete array declaration,
+/// similar to the output of `xxd -i`.
+///
+/// # Example
+///
+/// ```
+/// use hex::literal::CArray;
+///
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// assert_eq!(CArray::new().encode(b"Hello"), "0x48, 0x65, 0x6c, 0x6c, 0x6f");
+/// assert_eq!(CArray::new().per_line(2).encode(b"Hello"), "0x48, 0x65,\n0x6c, 0x6c,\n0x6f");
+///
+/// let array = CArray::new().per_line(4).declaration("const u8", "hello");
+/// assert_eq!(
+///     array.encode(b"Hello"),
+///     "const u8 hello[] = {\n  0x48, 0x65, 0x6c, 0x6c,\n  0x6f\n};"
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CArray<'a> {
+    case: Case,
+    per_line: usize,
+    declaration: Option<(&'a str, &'a str)>,
+}
+
+impl<'a> CArray<'a> {
+    /// Creates a formatter writing 12 lowercase values per line, without a
+    /// declaration.
+    pub const fn new() -> CArray<'a> {
+        CArray {
+            case: Case::Lower,
+            per_line: 12,
+            declaration: None,
+        }
+    }
+
+    /// Sets the letter casing of the encoded digits.
+    ///
+    /// The `0x` prefix is always lowercase.
+    #[must_use]
+    pub const fn case(mut self, case: Case) -> CArray<'a> {
+        self.case = case;
+        self
+    }
+
+    /// Sets the number of values written on a single line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is zero.
+    #[must_use]
+    pub const fn per_line(mut self, values: usize) -> CArray<'a> {
+        assert!(values > 0, "values per line must not be zero");
+        self.per_line = values;
+        self
+    }
+
+    /// Wraps the values into the declaration of an array called `name`, with
+    /// elements of type `ty` (e.g. `unsigned char` or `const uint8_t`).
+    ///
+    /// The values are indented by two spaces.
+    #[must_use]
+    pub const fn declaration(mut self, ty: &'a str, name: &'a str) -> CArray<'a> {
+        self.declaration = Some((ty, name));
+        self
+    }
+
+    /// Encodes `data` into a newly allocated `String`.
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn encode<T: AsRef<[u8]>>(&self, data: T) -> String {
+        let data = data.as_ref();
+        let mut buf = String::with_capacity(data.len() * 6);
+
+        // writing into a `String` never fails
+        let _ = self.encode_to_fmt(data, &mut buf);
+        buf
+    }
+
+    /// Writes the encoded `data` into a [`fmt::Write`] sink, without
+    /// allocating.
+    pub fn encode_to_fmt<T, W>(&self, data: T, w: &mut W) -> fmt::Result
+    where
+        T: AsRef<[u8]>,
+        W: fmt::Write + ?Sized,
+    {
+        let table = self.case.table();
+        let indent = match self.declaration {
+            Some((ty, name)) => {
+                writeln!(w, "{} {}[] = {{", ty, name)?;
+                "  "
+            }
+            None => "",
+        };
+
+        for (i, line) in data.as_ref().chunks(self.per_line).enumerate() {
+            if i > 0 {
+                w.write_str(",\n")?;
+            }
+            w.write_str(indent)?;
+
+            for (j, &byte) in line.iter().enumerate() {
+                if j > 0 {
+                    w.write_str(", ")?;
+                }
+                let (high, low) = byte2hex(byte, table);
+                w.write_str("0x")?;
+                w.write_char(high as char)?;
+                w.write_char(low as char)?;
+            }
+        }
+
+        match self.declaration {
+            Some(_) if data.as_ref().is_empty() => w.write_str("};"),
+            Some(_) => w.write_str("\n};"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for CArray<'_> {
+    fn default() -> Self {
+        CArray::new()
+    }
+}
+
+/// A formatter for Rust byte array and byte string literals.
+///
+/// The output is written on a single line, ready to be embedded into
+/// generated code, e.g. by a build script.
+///
+/// # Example
+///
+/// ```
+/// use hex::literal::RustLiteral;
+///
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// assert_eq!(RustLiteral::array().encode(b"Hi!"), "[0x48, 0x69, 0x21]");
+/// assert_eq!(RustLiteral::byte_string().encode(b"Hi!"), r#"b"\x48\x69\x21""#);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RustLiteral {
+    case: Case,
+    byte_string: bool,
+}
+
+impl RustLiteral {
+    /// Creates a formatter for array literals like `[0x48, 0x69]`.
+    pub const fn array() -> RustLiteral {
+        RustLiteral {
+            case: Case::Lower,
+            byte_string: false,
+        }
+    }
+
+    /// Creates a formatter for byte string literals like `b"\x48\x69"`.
+    ///
+    /// Every byte is escaped, including printable characters.
+    pub const fn byte_string() -> RustLiteral {
+        RustLiteral {
+            case: Case::Lower,
+            byte_string: true,
+        }
+    }
+
+    /// Sets the letter casing of the encoded digits.
+    ///
+    /// The `0x` and `\x` prefixes are always lowercase.
+    #[must_use]
+    pub const fn case(mut self, case: Case) -> RustLiteral {
+        self.case = case;
+        self
+    }
+
+    /// Encodes `data` into a newly allocated `String`.
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn encode<T: AsRef<[u8]>>(&self, data: T) -> String {
+        let data = data.as_ref();
+        let mut buf = String::with_capacity(data.len() * 6 + 3);
+
+        // writing into a `String` never fails
+        let _ = self.encode_to_fmt(data, &mut buf);
+        buf
+    }
+
+    /// Writes the encoded `data` into a [`fmt::Write`] sink, without
+    /// allocating.
+    pub fn encode_to_fmt<T, W>(&self, data: T, w: &mut W) -> fmt::Result
+    where
+        T: AsRef<[u8]>,
+        W: fmt::Write + ?Sized,
+    {
+        let table = self.case.table();
+        let (open, separator, prefix, close) = if self.byte_string {
+            ("b\"", "", "\\x", "\"")
+        } else {
+            ("[", ", ", "0x", "]")
+        };
+
+        w.write_str(open)?;
+        for (i, &byte) in data.as_ref().iter().enumerate() {
+            if i > 0 {
+                w.write_str(separator)?;
+            }
+            let (high, low) = byte2hex(byte, table);
+            w.write_str(prefix)?;
+            w.write_char(high as char)?;
+            w.write_char(low as char)?;
+        }
+        w.write_str(close)
+    }
+}
+
+impl Default for RustLiteral {
+    fn default() -> Self {
+        RustLiteral::array()
+    }
+}
+
+/// A hex number literal read by [`parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg(feature = "alloc")]
+pub struct Literal {
+    bytes: Vec<u8>,
+    width: Option<u32>,
+}
+
+#[cfg(feature = "alloc")]
+impl Literal {
+    /// Returns the value of the literal as big-endian bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the value of the literal as big-endian bytes, consuming the
+    /// literal.
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Returns the width in bits declared by the literal, if any.
+    pub const fn width(&self) -> Option<u32> {
+        self.width
+    }
+}
+
+/// Parses a hex number literal in assembly or Verilog notation.
+///
+/// The following forms are accepted, with upper- or lowercase digits:
+///
+/// - `DEADh` or `0DEADh`, the suffix notation of Intel-style assemblers,
+/// - `$DEAD`, the prefix notation of Motorola-style assemblers,
+/// - `16'hDEAD` and unsized `'hDEAD`, the based literals of Verilog. The
+///   digits may be separated by underscores, and the literal may be marked
+///   as signed with `'sh`.
+///
+/// The value is returned as big-endian bytes, an odd number of digits is
+/// padded with a leading zero. If the literal declares a width, the value
+/// is resized to exactly as many bytes as needed for that width. Widths
+/// above 65536 bits, the smallest limit Verilog tools have to support for
+/// vectors, are rejected.
+///
+/// # Errors
+///
+/// Returns [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) if the
+/// literal isn't in one of the above forms or contains an invalid digit, and
+/// [`InvalidStringLength`](FromHexError::InvalidStringLength) if there are
+/// no digits, the declared width is too large, or the value doesn't fit into
+/// the declared width.
+///
+/// # Example
+///
+/// ```
+/// use hex::literal::parse;
+///
+/// let literal = parse("0FFFh").unwrap();
+/// assert_eq!(literal.bytes(), [0x0f, 0xff]);
+/// assert_eq!(literal.width(), None);
+///
+/// assert_eq!(parse("$c000").unwrap().bytes(), [0xc0, 0x00]);
+///
+/// let literal = parse("24'hdead_ef").unwrap();
+/// assert_eq!(literal.bytes(), [0xde, 0xad, 0xef]);
+/// assert_eq!(literal.width(), Some(24));
+///
+/// assert_eq!(parse("12'hF").unwrap().bytes(), [0x00, 0x0f]);
+/// assert!(parse("4'hFF").is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse<T: AsRef<[u8]>>(text: T) -> Result<Literal, FromHexError> {
+    let text = text.as_ref();
+
+    if let Some(quote) = text.iter().position(|&c| c == b'\'') {
+        return parse_verilog(text, quote);
+    }
+
+    let bytes = match text {
+        [b'$', digits @ ..] => decode_digits(digits, 1, false)?,
+        [digits @ .., b'h' | b'H'] => decode_digits(digits, 0, false)?,
+        [] => return Err(FromHexError::InvalidStringLength),
+        [.., c] => {
+            return Err(FromHexError::InvalidHexCharacter {
+                c: *c as char,
+                index: text.len() - 1,
+            })
+        }
+    };
+    Ok(Literal { bytes, width: None })
+}
+
+// the widest Verilog literal `parse` accepts, the declared width is used as
+// the allocation size so it must not come from the input unchecked.
+#[cfg(feature = "alloc")]
+const MAX_WIDTH: u32 = 1 << 16;
+
+// parses a Verilog literal with the `'` at `quote`.
+#[cfg(feature = "alloc")]
+fn parse_verilog(text: &[u8], quote: usize) -> Result<Literal, FromHexError> {
+    let (size, rest) = text.split_at(quote);
+
+    let mut width = None;
+    for (i, &c) in size.iter().enumerate() {
+        let digit = match c {
+            b'0'..=b'9' => u32::from(c - b'0'),
+            _ => {
+                return Err(FromHexError::InvalidHexCharacter {
+                    c: c as char,
+                    index: i,
+                })
+            }
+        };
+        width = width
+            .unwrap_or(0_u32)
+            .checked_mul(10)
+            .and_then(|width| width.checked_add(digit));
+        if width.is_none() {
+            return Err(FromHexError::InvalidStringLength);
+        }
+    }
+
+    // skip the signedness flag
+    let base = match rest.get(1) {
+        Some(b's' | b'S') => 2,
+        _ => 1,
+    };
+    match rest.get(base) {
+        Some(b'h' | b'H') => {}
+        Some(&c) => {
+            return Err(FromHexError::InvalidHexCharacter {
+                c: c as char,
+                index: quote + base,
+            })
+        }
+        None => return Err(FromHexError::InvalidStringLength),
+    }
+    let mut bytes = decode_digits(&rest[base + 1..], quote + base + 1, true)?;
+
+    if let Some(width) = width {
+        if width > MAX_WIDTH {
+            return Err(FromHexError::InvalidStringLength);
+        }
+        // can't overflow with the width bounded above
+        let len = (width as usize + 7) / 8;
+        let leading = bytes.iter().take_while(|&&byte| byte == 0).count();
+        let bits = bytes.get(leading).map_or(0, |byte| {
+            (bytes.len() - leading) * 8 - byte.leading_zeros() as usize
+        });
+
+        if width == 0 || bits > width as usize {
+            return Err(FromHexError::InvalidStringLength);
+        }
+
+        if bytes.len() > len {
+            bytes.drain(..bytes.len() - len);
+        } else {
+            bytes.splice(..0, vec![0; len - bytes.len()]);
+        }
+    }
+
+    Ok(Literal { bytes, width })
+}
+
+// decodes the digits of a number, padding an odd number of digits with a
+// leading zero. `offset` is the position of `digits` in the literal.
+#[cfg(feature = "alloc")]
+fn decode_digits(digits: &[u8], offset: usize, underscores: bool) -> Result<Vec<u8>, FromHexError> {
+    let skips = |c: u8| underscores && c == b'_';
+
+    let count = digits.iter().filter(|&&c| !skips(c)).count();
+    if count == 0 {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    let mut out = vec![0; (count + 1) / 2];
+    // the first digit goes into the low nibble if the count is odd
+    let mut nibble = count % 2;
+    for (i, &c) in digits.iter().enumerate() {
+        if skips(c) {
+            continue;
+        }
+
+        let byte = &mut out[nibble / 2];
+        *byte = *byte << 4 | val(c, offset + i)?;
+        nibble += 1;
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_assembly() {
+        let literal = parse("0DEADh").unwrap();
+        assert_eq!(literal.bytes(), [0x00, 0xde, 0xad]);
+        assert_eq!(literal.width(), None);
+        assert_eq!(parse("fH").unwrap().into_bytes(), [0x0f]);
+        assert_eq!(parse("$00ff").unwrap().into_bytes(), [0x00, 0xff]);
+
+        assert_eq!(parse(""), Err(FromHexError::InvalidStringLength));
+        assert_eq!(parse("h"), Err(FromHexError::InvalidStringLength));
+        assert_eq!(parse("$"), Err(FromHexError::InvalidStringLength));
+        assert_eq!(
+            parse("DEAD"),
+            Err(FromHexError::InvalidHexCharacter { c: 'D', index: 3 })
+        );
+        assert_eq!(
+            parse("$DE_AD"),
+            Err(FromHexError::InvalidHexCharacter { c: '_', index: 3 })
+        );
+        assert_eq!(
+            parse("0x10h"),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_parse_verilog() {
+        let literal = parse("'hABC").unwrap();
+        assert_eq!(literal.bytes(), [0x0a, 0xbc]);
+        assert_eq!(literal.width(), None);
+
+        let literal = parse("8'sHff").unwrap();
+        assert_eq!(literal.bytes(), [0xff]);
+        assert_eq!(literal.width(), Some(8));
+
+        assert_eq!(parse("1'h1").unwrap().bytes(), [0x01]);
+        assert_eq!(parse("9'h1ff").unwrap().bytes(), [0x01, 0xff]);
+        assert_eq!(parse("32'h_ff").unwrap().bytes(), [0, 0, 0, 0xff]);
+        assert_eq!(parse("8'h0000ff").unwrap().bytes(), [0xff]);
+        assert_eq!(parse("16'h0").unwrap().bytes(), [0, 0]);
+
+        assert_eq!(parse("8'h100"), Err(FromHexError::InvalidStringLength));
+        assert_eq!(parse("0'h0"), Err(FromHexError::InvalidStringLength));
+        assert_eq!(parse("65536'h1").unwrap().bytes().len(), 8192);
+        assert_eq!(parse("65537'h1"), Err(FromHexError::InvalidStringLength));
+        assert_eq!(
+            parse("4000000000'h1"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(parse("8'h"), Err(FromHexError::InvalidStringLength));
+        assert_eq!(parse("8'h_"), Err(FromHexError::InvalidStringLength));
+        assert_eq!(parse("8'"), Err(FromHexError::InvalidStringLength));
+        assert_eq!(
+            parse("99999999999'h0"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            parse("8'b1010"),
+            Err(FromHexError::InvalidHexCharacter { c: 'b', index: 2 })
+        );
+        assert_eq!(
+            parse("8'shxz"),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 4 })
+        );
+        assert_eq!(
+            parse("a'h0"),
+            Err(FromHexError::InvalidHexCharacter { c: 'a', index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_c_array() {
+        assert_eq!(CArray::new().encode([]), "");
+        assert_eq!(CArray::new().encode([0xab]), "0xab");
+        assert_eq!(
+            CArray::new().case(Case::Upper).encode([0xab, 0xcd]),
+            "0xAB, 0xCD"
+        );
+        assert_eq!(
+            CArray::new().encode([0; 13]),
+            "0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,\n0x00"
+        );
+        assert_eq!(CArray::new().per_line(1).encode(b"ab"), "0x61,\n0x62");
+    }
+
+    #[test]
+    fn test_c_array_declaration() {
+        let array = CArray::new()
+            .per_line(2)
+            .declaration("unsigned char", "kiwi");
+        assert_eq!(
+            array.encode(b"kiwi"),
+            "unsigned char kiwi[] = {\n  0x6b, 0x69,\n  0x77, 0x69\n};"
+        );
+        assert_eq!(array.encode([]), "unsigned char kiwi[] = {\n};");
+    }
+
+    #[test]
+    fn test_rust_literal() {
+        assert_eq!(RustLiteral::array().encode([]), "[]");
+        assert_eq!(RustLiteral::array().encode([0x0a]), "[0x0a]");
+        assert_eq!(
+            RustLiteral::array().case(Case::Upper).encode([0xab, 0xcd]),
+            "[0xAB, 0xCD]"
+        );
+
+        assert_eq!(RustLiteral::byte_string().encode([]), r#"b"""#);
+        assert_eq!(
+            RustLiteral::byte_string()
+                .case(Case::Upper)
+                .encode([0xab, 0x22]),
+            r#"b"\xAB\x22""#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "values per line must not be zero")]
+    fn test_c_array_zero_per_line() {
+        let _ = CArray::new().per_line(0);
+    }
+}
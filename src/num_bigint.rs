@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Support for `num-bigint` types.
+//!
+//! Big integers are transcoded as big-endian hex numbers. When decoding, an
+//! odd number of digits is accepted as if padded with a leading zero.
+//!
+//! [`ToHex`](crate::ToHex) can't be implemented for the foreign integer
+//! types next to its blanket impl, so encoding is done with
+//! [`encode_biguint()`](crate::encode_biguint) and
+//! [`encode_bigint()`](crate::encode_bigint) instead.
+use alloc::string::String;
+
+use num_bigint::{BigInt, BigUint, Sign};
+
+use crate::{decode_left_padded, encode, FromHex, FromHexError};
+
+impl FromHex for BigUint {
+    type Error = FromHexError;
+
+    /// Decodes a non-empty big-endian hex number.
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        let hex = hex.as_ref();
+        if hex.is_empty() {
+            return Err(FromHexError::InvalidStringLength);
+        }
+
+        decode_left_padded(hex).map(|bytes| BigUint::from_bytes_be(&bytes))
+    }
+}
+
+impl FromHex for BigInt {
+    type Error = FromHexError;
+
+    /// Decodes a non-empty big-endian hex number, which may be negated by a
+    /// leading `-`.
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        match hex.as_ref() {
+            [b'-', digits @ ..] => BigUint::from_hex(digits)
+                .map(|value| -BigInt::from(value))
+                .map_err(|err| err.offset(1)),
+            digits => BigUint::from_hex(digits).map(BigInt::from),
+        }
+    }
+}
+
+/// Encodes a [`BigUint`] as big-endian hex number, using lowercase
+/// characters.
+///
+/// The number is encoded as whole bytes, so the result always has an even
+/// number of digits, and zero is encoded as `00`.
+///
+/// # Example
+///
+/// ```
+/// use num_bigint::BigUint;
+///
+/// assert_eq!(hex::encode_biguint(&BigUint::from(0xabcu32)), "0abc");
+/// ```
+#[must_use]
+pub fn encode_biguint(value: &BigUint) -> String {
+    encode(value.to_bytes_be())
+}
+
+/// Encodes a [`BigInt`] as big-endian hex number, using lowercase characters.
+///
+/// The magnitude is encoded like [`encode_biguint()`], prefixed with `-` for
+/// negative numbers.
+///
+/// # Example
+///
+/// ```
+/// use num_bigint::BigInt;
+///
+/// assert_eq!(hex::encode_bigint(&BigInt::from(-0x1234)), "-1234");
+/// ```
+#[must_use]
+pub fn encode_bigint(value: &BigInt) -> String {
+    let (sign, bytes) = value.to_bytes_be();
+    match sign {
+        Sign::Minus => ["-", &encode(bytes)].concat(),
+        Sign::NoSign | Sign::Plus => encode(bytes),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_hex_biguint() {
+        assert_eq!(BigUint::from_hex("0"), Ok(BigUint::from(0u8)));
+        assert_eq!(BigUint::from_hex("abc"), Ok(BigUint::from(0xabcu32)));
+        assert_eq!(BigUint::from_hex("0000ff"), Ok(BigUint::from(0xffu32)));
+        assert_eq!(
+            BigUint::from_hex("ffffffffffffffffffffffffffffffffff"),
+            Ok((BigUint::from(1u8) << 136) - 1u8)
+        );
+
+        assert_eq!(
+            BigUint::from_hex(""),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            BigUint::from_hex("-1"),
+            Err(FromHexError::InvalidHexCharacter { c: '-', index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_from_hex_bigint() {
+        assert_eq!(BigInt::from_hex("7f"), Ok(BigInt::from(0x7f)));
+        assert_eq!(BigInt::from_hex("-abc"), Ok(BigInt::from(-0xabc)));
+        assert_eq!(BigInt::from_hex("-0"), Ok(BigInt::from(0)));
+
+        assert_eq!(
+            BigInt::from_hex("-"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            BigInt::from_hex("-12g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 })
+        );
+    }
+
+    #[test]
+    fn test_encode_bigint() {
+        assert_eq!(encode_biguint(&BigUint::from(0u8)), "00");
+        assert_eq!(encode_biguint(&BigUint::from(0x10000u32)), "010000");
+        assert_eq!(encode_bigint(&BigInt::from(0)), "00");
+        assert_eq!(encode_bigint(&BigInt::from(255)), "ff");
+        assert_eq!(encode_bigint(&BigInt::from(-1)), "-01");
+
+        let value = BigInt::from(-0x0123456789abcdef_i64) << 100;
+        assert_eq!(BigInt::from_hex(encode_bigint(&value)), Ok(value));
+    }
+}
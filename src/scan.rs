@@ -0,0 +1,302 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Finding hex blobs in free-form text.
+//!
+//! Hashes, keys and other binary values end up in log files, chat messages
+//! and bug reports, surrounded by arbitrary text. The [`Scanner`] picks them
+//! out, reporting where each blob was found.
+//!
+//! # Example
+//!
+//! ```
+//! let text = "commit 0x1a2b3c4d by kiwi, parent deadbeefcafe";
+//! let blobs: Vec<_> = hex::scan::scan(text).map(|blob| blob.as_str()).collect();
+//!
+//! assert_eq!(blobs, ["0x1a2b3c4d", "deadbeefcafe"]);
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use core::{iter, ops::Range};
+
+use crate::{decode_to_slice, FromHexError};
+
+/// A configurable scanner for hex blobs.
+///
+/// A blob is a whole word consisting of an even number of hex digits,
+/// optionally prefixed with `0x` or `0X`. Words are runs of ASCII
+/// alphanumeric characters, underscores and non-ASCII characters, so hex
+/// digits which are part of a longer identifier, like the `cafe` in
+/// `cafeteria`, aren't reported.
+///
+/// By default blobs have to decode to at least 4 bytes, and the `0x` prefix
+/// is optional.
+///
+/// # Example
+///
+/// ```
+/// use hex::scan::Scanner;
+///
+/// let text = "a: 0xc0ffee, b: deadbeef, c: face";
+///
+/// let scanner = Scanner::new().min_len(2);
+/// let blobs: Vec<_> = scanner.scan(text).map(|blob| blob.range()).collect();
+/// assert_eq!(blobs, [3..11, 16..24, 29..33]);
+///
+/// let scanner = Scanner::new().min_len(2).require_prefix(true);
+/// let blobs: Vec<_> = scanner.scan(text).map(|blob| blob.digits()).collect();
+/// assert_eq!(blobs, ["c0ffee"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scanner {
+    min_len: usize,
+    require_prefix: bool,
+}
+
+impl Scanner {
+    /// Creates a scanner for blobs of at least 4 bytes, with an optional
+    /// `0x` prefix.
+    pub const fn new() -> Scanner {
+        Scanner {
+            min_len: 4,
+            require_prefix: false,
+        }
+    }
+
+    /// Sets the minimum number of bytes a blob has to decode to.
+    ///
+    /// Empty blobs are never reported.
+    #[must_use]
+    pub const fn min_len(mut self, min_len: usize) -> Scanner {
+        self.min_len = min_len;
+        self
+    }
+
+    /// Sets whether only blobs with a `0x` prefix are reported.
+    #[must_use]
+    pub const fn require_prefix(mut self, require_prefix: bool) -> Scanner {
+        self.require_prefix = require_prefix;
+        self
+    }
+
+    /// Returns an iterator over the blobs found in `text`, in order.
+    pub fn scan<'a>(&self, text: &'a str) -> Blobs<'a> {
+        Blobs {
+            scanner: *self,
+            text,
+            pos: 0,
+        }
+    }
+
+    // whether `word` is a blob, and the length of its prefix.
+    fn matches(&self, word: &str) -> Option<usize> {
+        let prefix_len = match word.as_bytes() {
+            [b'0', b'x' | b'X', ..] => 2,
+            _ if self.require_prefix => return None,
+            _ => 0,
+        };
+        let digits = &word.as_bytes()[prefix_len..];
+
+        let valid = !digits.is_empty()
+            && digits.len() % 2 == 0
+            && digits.len() / 2 >= self.min_len
+            && digits.iter().all(u8::is_ascii_hexdigit);
+        if valid {
+            Some(prefix_len)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Scanner {
+    fn default() -> Scanner {
+        Scanner::new()
+    }
+}
+
+/// Returns an iterator over the hex blobs found in `text`, using the default
+/// [`Scanner`] configuration.
+pub fn scan(text: &str) -> Blobs<'_> {
+    Scanner::new().scan(text)
+}
+
+/// An iterator over the hex blobs found in a text.
+///
+/// This struct is created by [`Scanner::scan`] and [`scan()`].
+#[derive(Debug, Clone)]
+pub struct Blobs<'a> {
+    scanner: Scanner,
+    text: &'a str,
+    pos: usize,
+}
+
+// whether `byte` continues a word.
+fn is_word(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || !byte.is_ascii()
+}
+
+impl<'a> Iterator for Blobs<'a> {
+    type Item = Blob<'a>;
+
+    fn next(&mut self) -> Option<Blob<'a>> {
+        let bytes = self.text.as_bytes();
+
+        while self.pos < bytes.len() {
+            if !is_word(bytes[self.pos]) {
+                self.pos += 1;
+                continue;
+            }
+
+            let start = self.pos;
+            let len = bytes[start..]
+                .iter()
+                .position(|&byte| !is_word(byte))
+                .unwrap_or(bytes.len() - start);
+            self.pos = start + len;
+
+            // words are delimited by ASCII characters, so this is always
+            // on a char boundary
+            let word = &self.text[start..self.pos];
+            if let Some(prefix_len) = self.scanner.matches(word) {
+                return Some(Blob {
+                    text: word,
+                    start,
+                    prefix_len,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+impl iter::FusedIterator for Blobs<'_> {}
+
+/// A hex blob found by a [`Scanner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blob<'a> {
+    text: &'a str,
+    start: usize,
+    prefix_len: usize,
+}
+
+impl<'a> Blob<'a> {
+    /// Returns the blob as it appears in the text, including its prefix.
+    pub const fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Returns the hex digits of the blob, without its prefix.
+    pub fn digits(&self) -> &'a str {
+        &self.text[self.prefix_len..]
+    }
+
+    /// Returns the byte offset of the blob in the scanned text.
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte offset right after the blob in the scanned text.
+    pub const fn end(&self) -> usize {
+        self.start + self.text.len()
+    }
+
+    /// Returns the byte range of the blob in the scanned text.
+    pub const fn range(&self) -> Range<usize> {
+        self.start()..self.end()
+    }
+
+    /// Returns the number of bytes the blob decodes to.
+    pub const fn decoded_len(&self) -> usize {
+        (self.text.len() - self.prefix_len) / 2
+    }
+
+    /// Decodes the blob into a newly allocated `Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let blob = hex::scan::scan("key=0xDEADBEEF").next().unwrap();
+    /// assert_eq!(blob.decode(), [0xde, 0xad, 0xbe, 0xef]);
+    /// ```
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn decode(&self) -> Vec<u8> {
+        let mut out = vec![0; self.decoded_len()];
+        self.decode_to_slice(&mut out)
+            .expect("scanned blobs are valid hex");
+        out
+    }
+
+    /// Decodes the blob into `out`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidStringLength`](FromHexError::InvalidStringLength) if
+    /// the length of `out` isn't [`decoded_len`](Self::decoded_len).
+    pub fn decode_to_slice(&self, out: &mut [u8]) -> Result<(), FromHexError> {
+        decode_to_slice(self.digits(), out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_scan_words() {
+        let text = "cafeteria 0xcafe_babe deadbeef, c0ffee00x fa\u{e9}deadbeef";
+        let mut blobs = Scanner::new().min_len(1).scan(text);
+        let blob = blobs.next().unwrap();
+        assert_eq!(blob.as_str(), "deadbeef");
+        assert_eq!(blob.range(), 22..30);
+        assert_eq!(&text[blob.range()], "deadbeef");
+        assert_eq!(blobs.next(), None);
+        assert_eq!(blobs.next(), None);
+    }
+
+    #[test]
+    fn test_scan_prefix() {
+        let text = "0x 0X0A1b 0xa 0x0x00";
+        let mut blobs = Scanner::new().min_len(0).scan(text);
+        let blob = blobs.next().unwrap();
+        assert_eq!(blob.as_str(), "0X0A1b");
+        assert_eq!(blob.digits(), "0A1b");
+        assert_eq!(blob.range(), 3..9);
+        assert_eq!(blob.decoded_len(), 2);
+        assert_eq!(blobs.next(), None);
+    }
+
+    #[test]
+    fn test_scan_min_len() {
+        let text = "ab abcd abcdef";
+        assert_eq!(Scanner::new().scan(text).count(), 0);
+        assert_eq!(Scanner::new().min_len(2).scan(text).count(), 2);
+        assert_eq!(Scanner::new().min_len(3).scan(text).count(), 1);
+    }
+
+    #[test]
+    fn test_blob_decode_to_slice() {
+        let blob = scan("0x00ff7f80").next().unwrap();
+        let mut out = [0; 4];
+        assert_eq!(blob.decode_to_slice(&mut out), Ok(()));
+        assert_eq!(out, [0x00, 0xff, 0x7f, 0x80]);
+        assert_eq!(
+            blob.decode_to_slice(&mut [0; 3]),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_scan_decode() {
+        let text = "sha1: 2fd4e1c67a2d28fced849ee1bb76e7391b93eb12\nid: 0x0000002a";
+        let blobs: Vec<_> = scan(text).map(|blob| blob.decode()).collect();
+        assert_eq!(blobs.len(), 2);
+        assert_eq!(blobs[0].len(), 20);
+        assert_eq!(blobs[1], [0, 0, 0, 42]);
+    }
+}
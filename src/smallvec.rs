@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Support for `smallvec` types.
+use smallvec::{Array, SmallVec};
+
+use crate::{
+    decode_to_slice, decoded_len, encode_raw, FromHex, FromHexError, HEX_CHARS_LOWER,
+    HEX_CHARS_UPPER,
+};
+
+impl<A: Array<Item = u8>> FromHex for SmallVec<A> {
+    type Error = FromHexError;
+
+    /// Decodes inline as long as the bytes fit into `A`, and on the heap
+    /// otherwise.
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        let hex = hex.as_ref();
+
+        let mut out = SmallVec::from_elem(0, decoded_len(hex.len())?);
+        decode_to_slice(hex, &mut out)?;
+        Ok(out)
+    }
+}
+
+/// Encodes `data` as hex digits into a [`SmallVec`], using lowercase
+/// characters.
+///
+/// The digits are stored inline if they fit into `A`, so short values are
+/// encoded without allocating.
+///
+/// # Example
+///
+/// ```
+/// use smallvec::SmallVec;
+///
+/// let hex: SmallVec<[u8; 8]> = hex::encode_smallvec(b"kiwi");
+/// assert_eq!(&hex[..], b"6b697769");
+/// assert!(!hex.spilled());
+/// ```
+pub fn encode_smallvec<A: Array<Item = u8>, T: AsRef<[u8]>>(data: T) -> SmallVec<A> {
+    encode_smallvec_inner(data.as_ref(), HEX_CHARS_LOWER)
+}
+
+/// Encodes `data` as hex digits into a [`SmallVec`], using uppercase
+/// characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_smallvec()`].
+///
+/// # Example
+///
+/// ```
+/// use smallvec::SmallVec;
+///
+/// let hex: SmallVec<[u8; 4]> = hex::encode_upper_smallvec([0xca, 0xfe]);
+/// assert_eq!(&hex[..], b"CAFE");
+/// ```
+pub fn encode_upper_smallvec<A: Array<Item = u8>, T: AsRef<[u8]>>(data: T) -> SmallVec<A> {
+    encode_smallvec_inner(data.as_ref(), HEX_CHARS_UPPER)
+}
+
+fn encode_smallvec_inner<A: Array<Item = u8>>(data: &[u8], table: &[u8; 16]) -> SmallVec<A> {
+    let mut buf = SmallVec::from_elem(0, data.len() * 2);
+    encode_raw(data, &mut buf, table);
+    buf
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_hex_smallvec() {
+        let bytes = SmallVec::<[u8; 4]>::from_hex("6b697769").unwrap();
+        assert_eq!(&bytes[..], b"kiwi");
+        assert!(!bytes.spilled());
+
+        let bytes = SmallVec::<[u8; 2]>::from_hex("6b697769").unwrap();
+        assert_eq!(&bytes[..], b"kiwi");
+        assert!(bytes.spilled());
+
+        assert_eq!(
+            SmallVec::<[u8; 4]>::from_hex("6b6"),
+            Err(FromHexError::OddLength)
+        );
+        assert_eq!(
+            SmallVec::<[u8; 4]>::from_hex("6g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_encode_smallvec() {
+        let hex: SmallVec<[u8; 12]> = encode_smallvec("foobar");
+        assert_eq!(&hex[..], b"666f6f626172");
+        assert!(!hex.spilled());
+
+        let hex: SmallVec<[u8; 2]> = encode_upper_smallvec([0xab, 0xcd]);
+        assert_eq!(&hex[..], b"ABCD");
+        assert!(hex.spilled());
+    }
+}
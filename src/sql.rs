@@ -0,0 +1,238 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! SQL hex literals.
+//!
+//! Databases accept binary data in SQL statements as hex literals, but the
+//! exact syntax differs between them. The functions in this module produce
+//! and parse these literals, e.g. when moving blobs in and out of SQL dumps.
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+
+#[cfg(feature = "alloc")]
+use crate::{
+    decode_to_slice, encode_to_fmt_inner, val, FromHexError, HEX_CHARS_LOWER, HEX_CHARS_UPPER,
+};
+
+/// Encodes `data` as MySQL hex literal of the form `X'DEADBEEF'`.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::sql::encode_mysql_literal([0xde, 0xad, 0xbe, 0xef]), "X'DEADBEEF'");
+/// assert_eq!(hex::sql::encode_mysql_literal([]), "X''");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_mysql_literal<T: AsRef<[u8]>>(data: T) -> String {
+    let data = data.as_ref();
+    let mut buf = String::with_capacity(data.len() * 2 + 3);
+
+    buf.push_str("X'");
+    // writing into a `String` never fails
+    let _ = encode_to_fmt_inner(data, &mut buf, HEX_CHARS_UPPER);
+    buf.push('\'');
+    buf
+}
+
+/// Decodes a MySQL hex literal into raw bytes.
+///
+/// Both literal forms are accepted:
+///
+/// - `X'DEADBEEF'` (or `x'DEADBEEF'`) with an even number of digits,
+/// - `0xDEADBEEF` with at least one digit. An odd number of digits is padded
+///   with a leading zero, just like MySQL does.
+///
+/// The digits themselves may be upper- or lowercase. Positions reported in
+/// errors refer to the whole literal.
+///
+/// # Errors
+///
+/// Returns [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) if the
+/// input isn't a hex literal or contains an invalid digit, and
+/// [`InvalidStringLength`](FromHexError::InvalidStringLength) if the literal
+/// is truncated.
+///
+/// # Example
+///
+/// ```
+/// use hex::sql::decode_mysql_literal;
+///
+/// assert_eq!(decode_mysql_literal("X'DEADBEEF'"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(decode_mysql_literal("0xdeadbeef"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(decode_mysql_literal("0xfff"), Ok(vec![0x0f, 0xff]));
+/// assert!(decode_mysql_literal("'DEADBEEF'").is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_mysql_literal<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    let data = data.as_ref();
+
+    match data {
+        [b'X' | b'x', b'\'', rest @ ..] => {
+            let digits = match rest.split_last() {
+                Some((b'\'', digits)) => digits,
+                _ => return Err(FromHexError::InvalidStringLength),
+            };
+            crate::decode(digits).map_err(|err| err.offset(2))
+        }
+        [b'0', b'x', digits @ ..] => {
+            if digits.is_empty() {
+                return Err(FromHexError::InvalidStringLength);
+            }
+
+            // an odd digit is padded to a whole byte
+            let mut out = vec![0; (digits.len() + 1) / 2];
+            let (odd, pairs) = digits.split_at(digits.len() % 2);
+            let (padded, bytes) = out.split_at_mut(odd.len());
+            if let (Some(&c), Some(byte)) = (odd.first(), padded.first_mut()) {
+                *byte = val(c, 2)?;
+            }
+            decode_to_slice(pairs, bytes).map_err(|err| err.offset(2 + odd.len()))?;
+            Ok(out)
+        }
+        [b'X' | b'x' | b'0'] | [] => Err(FromHexError::InvalidStringLength),
+        [b'X' | b'x' | b'0', c, ..] => Err(FromHexError::InvalidHexCharacter {
+            c: *c as char,
+            index: 1,
+        }),
+        [c, ..] => Err(FromHexError::InvalidHexCharacter {
+            c: *c as char,
+            index: 0,
+        }),
+    }
+}
+
+/// Encodes `data` in the PostgreSQL `bytea` hex format, e.g. `\xdeadbeef`.
+///
+/// Just like PostgreSQL itself, lowercase digits are used.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(hex::sql::encode_bytea([0xde, 0xad, 0xbe, 0xef]), r"\xdeadbeef");
+/// assert_eq!(hex::sql::encode_bytea([]), r"\x");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_bytea<T: AsRef<[u8]>>(data: T) -> String {
+    let data = data.as_ref();
+    let mut buf = String::with_capacity(data.len() * 2 + 2);
+
+    buf.push_str("\\x");
+    // writing into a `String` never fails
+    let _ = encode_to_fmt_inner(data, &mut buf, HEX_CHARS_LOWER);
+    buf
+}
+
+/// Decodes a PostgreSQL `bytea` value in hex format into raw bytes.
+///
+/// The input has to start with `\x`, followed by an even number of upper- or
+/// lowercase digits. Values in the legacy escape format are rejected.
+/// Positions reported in errors refer to the whole value.
+///
+/// # Errors
+///
+/// Returns [`InvalidHexCharacter`](FromHexError::InvalidHexCharacter) if the
+/// `\x` prefix is missing or a digit is invalid, and the usual length errors
+/// of [`decode()`](crate::decode).
+///
+/// # Example
+///
+/// ```
+/// use hex::{sql::decode_bytea, FromHexError};
+///
+/// assert_eq!(decode_bytea(r"\xDEADbeef"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(
+///     decode_bytea(r"abc\000"),
+///     Err(FromHexError::InvalidHexCharacter { c: 'a', index: 0 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_bytea<T: AsRef<[u8]>>(data: T) -> Result<Vec<u8>, FromHexError> {
+    match data.as_ref() {
+        [b'\\', b'x', digits @ ..] => crate::decode(digits).map_err(|err| err.offset(2)),
+        [b'\\'] | [] => Err(FromHexError::InvalidStringLength),
+        [b'\\', c, ..] => Err(FromHexError::InvalidHexCharacter {
+            c: *c as char,
+            index: 1,
+        }),
+        [c, ..] => Err(FromHexError::InvalidHexCharacter {
+            c: *c as char,
+            index: 0,
+        }),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_mysql_literal() {
+        for data in [&[][..], &[0x00], b"kiwi"] {
+            assert_eq!(
+                decode_mysql_literal(encode_mysql_literal(data)).unwrap(),
+                data
+            );
+        }
+
+        assert_eq!(decode_mysql_literal("x'0aFf'"), Ok(vec![0x0a, 0xff]));
+        assert_eq!(decode_mysql_literal("0xA"), Ok(vec![0x0a]));
+        assert_eq!(decode_mysql_literal("0x0a0b"), Ok(vec![0x0a, 0x0b]));
+    }
+
+    #[test]
+    fn test_mysql_literal_invalid() {
+        assert_eq!(decode_mysql_literal("X'0'"), Err(FromHexError::OddLength));
+        assert_eq!(
+            decode_mysql_literal("X'0g'"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 })
+        );
+        assert_eq!(
+            decode_mysql_literal("0x12g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 4 })
+        );
+        assert_eq!(
+            decode_mysql_literal("0xg12"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 2 })
+        );
+        assert_eq!(
+            decode_mysql_literal("0X12"),
+            Err(FromHexError::InvalidHexCharacter { c: 'X', index: 1 })
+        );
+        assert_eq!(
+            decode_mysql_literal("DEADBEEF"),
+            Err(FromHexError::InvalidHexCharacter { c: 'D', index: 0 })
+        );
+        assert_eq!(
+            decode_mysql_literal("X'12"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_mysql_literal("0x"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_mysql_literal(""),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn test_bytea() {
+        for data in [&[][..], &[0x00], b"kiwi"] {
+            assert_eq!(decode_bytea(encode_bytea(data)).unwrap(), data);
+        }
+
+        assert_eq!(decode_bytea(r"\x0"), Err(FromHexError::OddLength));
+        assert_eq!(
+            decode_bytea(r"\x0g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 })
+        );
+        assert_eq!(
+            decode_bytea(r"\000"),
+            Err(FromHexError::InvalidHexCharacter { c: '0', index: 1 })
+        );
+        assert_eq!(decode_bytea(""), Err(FromHexError::InvalidStringLength));
+    }
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Support for `tinyvec` types.
+#[cfg(feature = "alloc")]
+use tinyvec::TinyVec;
+use tinyvec::{Array, ArrayVec};
+
+use crate::{decode_to_slice, decoded_len, FromHex, FromHexError};
+
+impl<A: Array<Item = u8>> FromHex for ArrayVec<A> {
+    type Error = FromHexError;
+
+    /// Decodes up to `A::CAPACITY` bytes, returning
+    /// [`InvalidStringLength`](FromHexError::InvalidStringLength) if the
+    /// bytes don't fit.
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        let hex = hex.as_ref();
+        let len = decoded_len(hex.len())?;
+        if len > A::CAPACITY {
+            return Err(FromHexError::InvalidStringLength);
+        }
+
+        let mut out = ArrayVec::new();
+        out.resize(len, 0);
+        decode_to_slice(hex, &mut out)?;
+        Ok(out)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: Array<Item = u8>> FromHex for TinyVec<A> {
+    type Error = FromHexError;
+
+    /// Decodes inline as long as the bytes fit into `A`, and on the heap
+    /// otherwise.
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        let hex = hex.as_ref();
+
+        let mut out = TinyVec::new();
+        out.resize(decoded_len(hex.len())?, 0);
+        decode_to_slice(hex, &mut out)?;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_hex_arrayvec() {
+        let bytes = ArrayVec::<[u8; 8]>::from_hex("6b697769").unwrap();
+        assert_eq!(&bytes[..], b"kiwi");
+        assert_eq!(ArrayVec::<[u8; 4]>::from_hex("").unwrap().len(), 0);
+
+        assert_eq!(
+            ArrayVec::<[u8; 3]>::from_hex("6b697769"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            ArrayVec::<[u8; 8]>::from_hex("6b6"),
+            Err(FromHexError::OddLength)
+        );
+        assert_eq!(
+            ArrayVec::<[u8; 8]>::from_hex("6b6g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_hex_tinyvec() {
+        let bytes = TinyVec::<[u8; 4]>::from_hex("6b697769").unwrap();
+        assert_eq!(&bytes[..], b"kiwi");
+        assert!(bytes.is_inline());
+
+        let bytes = TinyVec::<[u8; 2]>::from_hex("6b697769").unwrap();
+        assert_eq!(&bytes[..], b"kiwi");
+        assert!(bytes.is_heap());
+
+        assert_eq!(
+            TinyVec::<[u8; 4]>::from_hex("6b6"),
+            Err(FromHexError::OddLength)
+        );
+    }
+}
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Support for `uuid` types.
+//!
+//! [`Uuid`] already implements [`ToHex`](crate::ToHex) through
+//! `AsRef<[u8]>`, encoding the plain 32-digit form.
+use uuid::Uuid;
+
+use crate::{ident::decode_uuid, FromHex, FromHexError};
+
+impl FromHex for Uuid {
+    type Error = FromHexError;
+
+    /// Decodes either the hyphenated form, e.g.
+    /// `550e8400-e29b-41d4-a716-446655440000`, or the plain 32-digit form.
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        let hex = hex.as_ref();
+        let bytes = if hex.len() == 36 {
+            decode_uuid(hex)?
+        } else {
+            <[u8; 16]>::from_hex(hex)?
+        };
+        Ok(Uuid::from_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const UUID: Uuid = Uuid::from_bytes([
+        0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4, 0xa7, 0x16, 0x44, 0x66, 0x55, 0x44, 0x00,
+        0x00,
+    ]);
+
+    #[test]
+    fn test_from_hex_uuid() {
+        assert_eq!(
+            Uuid::from_hex("550e8400-e29b-41d4-a716-446655440000"),
+            Ok(UUID)
+        );
+        assert_eq!(Uuid::from_hex("550E8400E29B41D4A716446655440000"), Ok(UUID));
+
+        assert_eq!(
+            Uuid::from_hex("550e8400e29b41d4a71644665544000"),
+            Err(FromHexError::OddLength)
+        );
+        assert_eq!(
+            Uuid::from_hex("550e8400e29b41d4a7164466554400"),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            Uuid::from_hex("550e8400-e29b-41d4-a716_446655440000"),
+            Err(FromHexError::InvalidHexCharacter { c: '_', index: 23 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_to_hex_uuid() {
+        use crate::ToHex;
+        use alloc::string::String;
+
+        assert_eq!(
+            UUID.encode_hex::<String>(),
+            "550e8400e29b41d4a716446655440000"
+        );
+        assert_eq!(Uuid::from_hex(crate::encode(UUID)), Ok(UUID));
+    }
+}
@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Encoding slices of multi-byte words.
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "alloc")]
+use crate::{FromHexError, HEX_CHARS_LOWER, HEX_CHARS_UPPER};
+
+/// The byte order used to transcode multi-byte values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// The most significant byte comes first.
+    Big,
+    /// The least significant byte comes first.
+    Little,
+}
+
+mod private {
+    use super::Endian;
+
+    pub trait Sealed: Copy {
+        const SIZE: usize;
+        type Bytes: AsRef<[u8]>;
+
+        fn to_bytes(self, endian: Endian) -> Self::Bytes;
+        fn from_slice(bytes: &[u8], endian: Endian) -> Self;
+    }
+}
+
+/// Unsigned integer types which can be encoded with [`encode_words()`].
+///
+/// This trait is sealed and implemented for `u16`, `u32`, `u64` and `u128`.
+pub trait Word: private::Sealed {}
+
+macro_rules! impl_word {
+    ($($ty:ty)*) => {$(
+        impl private::Sealed for $ty {
+            const SIZE: usize = core::mem::size_of::<$ty>();
+            type Bytes = [u8; core::mem::size_of::<$ty>()];
+
+            fn to_bytes(self, endian: Endian) -> Self::Bytes {
+                match endian {
+                    Endian::Big => self.to_be_bytes(),
+                    Endian::Little => self.to_le_bytes(),
+                }
+            }
+
+            fn from_slice(bytes: &[u8], endian: Endian) -> Self {
+                let mut buf = [0; core::mem::size_of::<$ty>()];
+                buf.copy_from_slice(bytes);
+                match endian {
+                    Endian::Big => <$ty>::from_be_bytes(buf),
+                    Endian::Little => <$ty>::from_le_bytes(buf),
+                }
+            }
+        }
+
+        impl Word for $ty {}
+    )*};
+}
+
+impl_word!(u16 u32 u64 u128);
+
+/// Encodes a slice of words as hex string using lowercase characters.
+///
+/// Every word is encoded using `2 * size_of::<W>()` digits, with its bytes in
+/// the given byte order.
+///
+/// # Example
+///
+/// ```
+/// use hex::Endian;
+///
+/// let registers = [0xdeadbeef_u32, 0x00000001];
+///
+/// assert_eq!(hex::encode_words(&registers, Endian::Big), "deadbeef00000001");
+/// assert_eq!(hex::encode_words(&registers, Endian::Little), "efbeadde01000000");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_words<W: Word>(words: &[W], endian: Endian) -> String {
+    encode_words_inner(words, endian, HEX_CHARS_LOWER)
+}
+
+/// Encodes a slice of words as hex string using uppercase characters.
+///
+/// Apart from the characters' casing, this works exactly like
+/// [`encode_words()`].
+///
+/// # Example
+///
+/// ```
+/// use hex::Endian;
+///
+/// assert_eq!(hex::encode_upper_words(&[0xcafe_u16, 0x0a0b], Endian::Big), "CAFE0A0B");
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_upper_words<W: Word>(words: &[W], endian: Endian) -> String {
+    encode_words_inner(words, endian, HEX_CHARS_UPPER)
+}
+
+#[cfg(feature = "alloc")]
+fn encode_words_inner<W: Word>(words: &[W], endian: Endian, table: &'static [u8; 16]) -> String {
+    let mut buf = String::with_capacity(words.len() * W::SIZE * 2);
+    for word in words {
+        crate::encode_append_inner(&mut buf, word.to_bytes(endian).as_ref(), table);
+    }
+    buf
+}
+
+/// Decodes a hex string into a vector of words.
+///
+/// This is the inverse of [`encode_words()`]. The input has to contain
+/// exactly `2 * size_of::<W>()` digits per word, otherwise
+/// [`FromHexError::InvalidStringLength`] is returned.
+///
+/// # Example
+///
+/// ```
+/// use hex::Endian;
+///
+/// assert_eq!(hex::decode_words::<u16, _>("0102a0b0", Endian::Little), Ok(vec![0x0201, 0xb0a0]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_words<W: Word, T: AsRef<[u8]>>(
+    data: T,
+    endian: Endian,
+) -> Result<Vec<W>, FromHexError> {
+    let bytes = crate::decode(data)?;
+    if bytes.len() % W::SIZE != 0 {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    Ok(bytes
+        .chunks_exact(W::SIZE)
+        .map(|chunk| W::from_slice(chunk, endian))
+        .collect())
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_encode_words() {
+        assert_eq!(encode_words(&[0x0102_u16, 0xa0b0], Endian::Big), "0102a0b0");
+        assert_eq!(
+            encode_words(&[0x0102_u16, 0xa0b0], Endian::Little),
+            "0201b0a0"
+        );
+        assert_eq!(
+            encode_upper_words(&[0x0123456789abcdef_u64], Endian::Big),
+            "0123456789ABCDEF"
+        );
+        assert_eq!(encode_words(&[1_u128], Endian::Little).len(), 32);
+        assert_eq!(encode_words::<u32>(&[], Endian::Big), "");
+    }
+
+    #[test]
+    fn test_decode_words() {
+        assert_eq!(
+            decode_words::<u32, _>("deadbeef00000001", Endian::Big),
+            Ok(vec![0xdeadbeef, 1])
+        );
+        assert_eq!(
+            decode_words::<u32, _>("deadbeef0000", Endian::Big),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            decode_words::<u16, _>("dead0x", Endian::Big),
+            Err(FromHexError::InvalidHexCharacter { c: 'x', index: 5 })
+        );
+    }
+}
@@ -0,0 +1,46 @@
+#![cfg(all(feature = "derive", feature = "alloc"))]
+
+use hex::{FromHex, FromHexError, ToHex};
+
+#[derive(Debug, PartialEq, FromHex, ToHex)]
+#[hex(display)]
+struct TxId([u8; 4]);
+
+#[derive(Debug, PartialEq, FromHex, ToHex)]
+struct Blob {
+    bytes: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, FromHex, ToHex)]
+struct Wrapper<T>(T);
+
+#[test]
+fn from_hex() {
+    assert_eq!(TxId::from_hex("6b697769"), Ok(TxId(*b"kiwi")));
+    assert_eq!(
+        TxId::from_hex("6b69"),
+        Err(FromHexError::InvalidStringLength)
+    );
+    assert_eq!(
+        Blob::from_hex("6b69"),
+        Ok(Blob {
+            bytes: b"ki".to_vec()
+        })
+    );
+    assert_eq!(Wrapper::<[u8; 2]>::from_hex("6b69"), Ok(Wrapper(*b"ki")));
+}
+
+#[test]
+fn to_hex() {
+    assert_eq!(TxId(*b"kiwi").encode_hex::<String>(), "6b697769");
+    assert_eq!(TxId(*b"kiwi").encode_hex_upper::<String>(), "6B697769");
+    assert_eq!(TxId(*b"kiwi").to_string(), "6b697769");
+    assert_eq!(format!("0x{}", TxId([0xca, 0xfe, 0, 1])), "0xcafe0001");
+
+    let mut s = String::new();
+    Blob { bytes: vec![0xab] }
+        .encode_hex_upper_to(&mut s)
+        .unwrap();
+    assert_eq!(s, "AB");
+    assert_eq!(Wrapper(vec![1, 2]).encode_hex::<String>(), "0102");
+}
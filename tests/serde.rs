@@ -57,3 +57,55 @@ fn deserialize_upper() {
     let de: Bar = serde_json::from_str(r#"{"foo":"010A64"}"#).expect("deserialization failed");
     assert_eq!(de, bar);
 }
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Baz<'a> {
+    #[serde(with = "hex")]
+    bar: std::borrow::Cow<'a, [u8]>,
+}
+
+#[test]
+fn roundtrip_cow() {
+    let baz = Baz {
+        bar: std::borrow::Cow::Borrowed(&[1, 10, 100]),
+    };
+
+    let ser = serde_json::to_string(&baz).expect("serialization failed");
+    assert_eq!(ser, r#"{"bar":"010a64"}"#);
+
+    let de: Baz = serde_json::from_str(&ser).expect("deserialization failed");
+    assert_eq!(de, baz);
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Qux {
+    id: hex::HexString,
+}
+
+#[test]
+fn hex_string() {
+    let qux: Qux = serde_json::from_str(r#"{"id":"010A64"}"#).expect("deserialization failed");
+    assert_eq!(qux.id.decode(), [1, 10, 100]);
+
+    let ser = serde_json::to_string(&qux).expect("serialization failed");
+    assert_eq!(ser, r#"{"id":"010A64"}"#);
+
+    let err = serde_json::from_str::<Qux>(r#"{"id":"010"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("Odd number of digits"));
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Quux {
+    hash: hex::HexBytes<3>,
+}
+
+#[test]
+fn hex_bytes() {
+    let quux: Quux = serde_json::from_str(r#"{"hash":"010A64"}"#).expect("deserialization failed");
+    assert_eq!(quux.hash.0, [1, 10, 100]);
+
+    let ser = serde_json::to_string(&quux).expect("serialization failed");
+    assert_eq!(ser, r#"{"hash":"010a64"}"#);
+
+    assert!(serde_json::from_str::<Quux>(r#"{"hash":"010a"}"#).is_err());
+}